@@ -8,6 +8,22 @@ pub const COMMENT_SEED: &[u8] = b"comment";
 pub const FOLLOW_SEED: &[u8] = b"follow";
 pub const POLL_SEED: &[u8] = b"poll";
 pub const VOTE_SEED: &[u8] = b"vote";
+pub const POLL_RESULT_SEED: &[u8] = b"poll_result";
+pub const VOTE_ESCROW_SEED: &[u8] = b"vote_escrow";
+pub const PROPOSAL_SEED: &[u8] = b"proposal";
+pub const PRIZE_SEED: &[u8] = b"prize";
+pub const PRIZE_VAULT_SEED: &[u8] = b"prize_vault";
+pub const BOUNTY_SEED: &[u8] = b"bounty";
+pub const UNLOCK_SEED: &[u8] = b"unlock";
+pub const SUBSCRIPTION_SEED: &[u8] = b"subscription";
+pub const TIP_RECORD_SEED: &[u8] = b"tip_record";
+pub const POST_MINT_SEED: &[u8] = b"post_mint";
+pub const REPOST_SEED: &[u8] = b"repost";
+pub const MODERATOR_SEED: &[u8] = b"moderator";
+pub const REPORT_SEED: &[u8] = b"report";
+pub const BAN_SEED: &[u8] = b"ban";
+pub const BLOCK_SEED: &[u8] = b"block";
+pub const FOLLOW_REQUEST_SEED: &[u8] = b"follow_request";
 
 // String Length Limits
 pub const MAX_DISPLAY_NAME_LEN: usize = 50;
@@ -17,19 +33,54 @@ pub const MAX_DESCRIPTION_URI_LEN: usize = 200;
 pub const MAX_CONTENT_URI_LEN: usize = 200;
 pub const MAX_PSEUDONYM_LEN: usize = 30;
 pub const MAX_QUESTION_URI_LEN: usize = 200;
+pub const MAX_EVIDENCE_URI_LEN: usize = 200;
 pub const MAX_POLL_OPTIONS: usize = 10;
+pub const MIN_POLL_OPTIONS: usize = 2;
+pub const MAX_POLL_OPTION_LABEL_LEN: usize = 100;
+pub const MAX_TIP_MEMO_LEN: usize = 140;
+pub const MAX_NFT_NAME_LEN: usize = 32;
+pub const MAX_NFT_SYMBOL_LEN: usize = 10;
+
+// Pinned Posts
+pub const MAX_PINNED_POSTS: usize = 5;
+pub const MAX_PROFILE_PINNED_POSTS: usize = 3;
+
+// Polls
+pub const POLL_CLOSE_GRACE_PERIOD: i64 = 30 * 24 * 60 * 60; // 30 days after end_time
+
+// Governance
+pub const MAX_THRESHOLD_BPS: u16 = 10_000;
+
+// Bounties
+pub const MAX_BOUNTY_DURATION: i64 = 90 * 24 * 60 * 60; // 90 days
+
+// Subscriptions
+pub const MAX_SUBSCRIPTION_TIERS: usize = 3;
 
 // Tipping
 pub const FIXED_TIP_AMOUNT: u64 = 2_000_000; // 0.002 SOL in lamports
 
 // Account Space Calculations (in bytes)
 // Base: 8 (discriminator)
-pub const PROFILE_SIZE: usize = 8 + 32 + 4 + MAX_DISPLAY_NAME_LEN + 4 + MAX_AVATAR_URI_LEN + 8 + 8 + 8;
-pub const COMMUNITY_SIZE: usize = 8 + 4 + MAX_COMMUNITY_NAME_LEN + 4 + MAX_DESCRIPTION_URI_LEN + 32 + 8 + 8 + 8 + 8;
+pub const PROFILE_SIZE: usize = 8 + 32 + 4 + MAX_DISPLAY_NAME_LEN + 4 + MAX_AVATAR_URI_LEN + 8 + 8 + 8 + 8 + 4 + (32 * MAX_PROFILE_PINNED_POSTS) + 1 + 4 + (16 * MAX_SUBSCRIPTION_TIERS) + 8;
+pub const COMMUNITY_SIZE: usize = 8 + 4 + MAX_COMMUNITY_NAME_LEN + 4 + MAX_DESCRIPTION_URI_LEN + 32 + 8 + 8 + 8 + 8 + 4 + (32 * MAX_PINNED_POSTS) + 4 + 1 + 8 + 4 + 2 + 8;
 pub const MEMBERSHIP_SIZE: usize = 8 + 32 + 32 + 8;
-pub const POST_SIZE: usize = 8 + 32 + 8 + 4 + MAX_CONTENT_URI_LEN + 32 + 1 + 32 + 1 + 4 + MAX_PSEUDONYM_LEN + 8 + 8 + 8 + 8;
+pub const MODERATOR_SIZE: usize = 8 + 32 + 32 + 32 + 8 + 9;
+pub const POST_SIZE: usize = 8 + 32 + 8 + 4 + MAX_CONTENT_URI_LEN + 32 + 1 + 32 + 1 + 4 + MAX_PSEUDONYM_LEN + 8 + 8 + 8 + 8 + 8 + 1 + 1 + 1 + 8 + 4 + 1 + 8 + 8 + 8 + 1 + 33 + 1;
+pub const BOUNTY_SIZE: usize = 8 + 32 + 32 + 8 + 8 + 8;
+pub const UNLOCK_SIZE: usize = 8 + 32 + 32 + 8 + 8;
+pub const SUBSCRIPTION_SIZE: usize = 8 + 32 + 32 + 1 + 8 + 8 + 8;
+pub const TIP_RECORD_SIZE: usize = 8 + 32 + 32 + 8 + 4 + 8 + 8;
+pub const REPOST_SIZE: usize = 8 + 32 + 32 + 1 + 32 + 1 + 4 + MAX_CONTENT_URI_LEN + 1 + 32 + 8;
 pub const LIKE_SIZE: usize = 8 + 32 + 32 + 8;
-pub const COMMENT_SIZE: usize = 8 + 32 + 32 + 8 + 4 + MAX_CONTENT_URI_LEN + 32 + 8;
+pub const COMMENT_SIZE: usize = 8 + 32 + 32 + 8 + 4 + MAX_CONTENT_URI_LEN + 32 + 8 + 4 + 1 + 8 + 1;
 pub const FOLLOW_SIZE: usize = 8 + 32 + 32 + 8;
-pub const POLL_SIZE: usize = 8 + 32 + 8 + 4 + MAX_QUESTION_URI_LEN + 4 + (32 * MAX_POLL_OPTIONS) + 4 + (4 * MAX_POLL_OPTIONS) + 32 + 8 + 8;
-pub const VOTE_SIZE: usize = 8 + 32 + 32 + 1 + 8;
\ No newline at end of file
+pub const FOLLOW_REQUEST_SIZE: usize = 8 + 32 + 32 + 8;
+pub const BLOCK_SIZE: usize = 8 + 32 + 32 + 8;
+pub const POLL_SIZE: usize = 8 + 32 + 8 + 4 + MAX_QUESTION_URI_LEN + 4 + (32 * MAX_POLL_OPTIONS) + 4 + (4 * MAX_POLL_OPTIONS) + 32 + 8 + 8 + 1 + 4 + ((4 + MAX_POLL_OPTION_LABEL_LEN) * MAX_POLL_OPTIONS) + 1 + 1 + 4 + 1 + 9 + 2 + 4 + 33 + 9 + 4 + 8 + 4 + 8 + 1 + 1 + 8 + 1 + 2 + 4 + (8 * MAX_POLL_OPTIONS);
+pub const VOTE_SIZE: usize = 8 + 32 + 32 + 1 + 8 + 2 + 4 + MAX_POLL_OPTIONS + 1 + 8 + 33;
+pub const POLL_RESULT_SIZE: usize = 8 + 32 + 1 + 4 + 4 + (4 * MAX_POLL_OPTIONS) + 4 + 2 + 4 + ((4 + 4 * MAX_POLL_OPTIONS) * MAX_POLL_OPTIONS) + 2 + 9;
+pub const PRIZE_SIZE: usize = 8 + 32 + 32 + 33 + 8 + 8;
+pub const PROPOSAL_SIZE: usize = 8 + 32 + 32 + 32 + 1 + 4 + MAX_DESCRIPTION_URI_LEN + 2 + 8 + 8 + 9;
+pub const REPORT_SIZE: usize = 8 + 32 + 32 + 1 + 1 + 32 + 32 + 1 + 4 + MAX_EVIDENCE_URI_LEN + 1 + 1 + 32 + 8 + 1 + 8 + 1;
+pub const BAN_SIZE: usize = 8 + 32 + 32 + 32 + 8;
\ No newline at end of file
@@ -36,4 +36,196 @@ pub enum SocialError {
     InvalidPollOption,
     #[msg("Arithmetic overflow")]
     ArithmeticOverflow,
+    #[msg("Quote URI and quote hash must be provided together")]
+    InvalidQuote,
+    #[msg("User is not a member of this community")]
+    NotCommunityMember,
+    #[msg("Only the community creator can perform this action")]
+    NotCommunityCreator,
+    #[msg("Only moderators can perform this action")]
+    NotModerator,
+    #[msg("Only the post author can perform this action")]
+    NotPostAuthor,
+    #[msg("Post does not belong to this community")]
+    PostNotInCommunity,
+    #[msg("Maximum number of pinned posts reached")]
+    PinnedPostsFull,
+    #[msg("Post is already pinned")]
+    PostAlreadyPinned,
+    #[msg("Post is not pinned")]
+    PostNotPinned,
+    #[msg("Only the post author or a moderator can perform this action")]
+    NotAuthorOrModerator,
+    #[msg("Post is locked")]
+    PostLocked,
+    #[msg("Post is not locked")]
+    PostNotLocked,
+    #[msg("Lock expiry must be in the future")]
+    InvalidLockExpiry,
+    #[msg("Evidence URI is too long")]
+    EvidenceUriTooLong,
+    #[msg("Report has already been resolved")]
+    ReportAlreadyResolved,
+    #[msg("Account does not match the report target")]
+    ReportTargetMismatch,
+    #[msg("Cannot ban the author of anonymous content")]
+    CannotBanAnonymousAuthor,
+    #[msg("Ban and author accounts are required to ban the author")]
+    BanAccountRequired,
+    #[msg("Ban accounts are only allowed when banning the author")]
+    UnexpectedBanAccount,
+    #[msg("User is banned from this community")]
+    UserBanned,
+    #[msg("Cannot block yourself")]
+    CannotBlockSelf,
+    #[msg("You have been blocked by this user")]
+    BlockedByUser,
+    #[msg("Block record account is missing or invalid")]
+    InvalidBlockAccount,
+    #[msg("This profile requires follow requests to be approved")]
+    FollowRequiresApproval,
+    #[msg("This profile does not require follow approval")]
+    FollowApprovalNotRequired,
+    #[msg("Poll options do not match the poll option kind")]
+    PollOptionKindMismatch,
+    #[msg("Poll option label is too long")]
+    PollOptionLabelTooLong,
+    #[msg("Too few poll options")]
+    TooFewPollOptions,
+    #[msg("Duplicate poll option")]
+    DuplicatePollOption,
+    #[msg("Remaining accounts must list each option profile, optionally followed by its membership")]
+    OptionAccountsMismatch,
+    #[msg("Poll option is not a valid profile")]
+    InvalidOptionProfile,
+    #[msg("Max selections must be between 1 and the number of options")]
+    InvalidMaxSelections,
+    #[msg("Too many options selected for this poll")]
+    TooManySelections,
+    #[msg("Instruction does not support this poll's voting mode")]
+    PollModeMismatch,
+    #[msg("Poll has not ended yet")]
+    PollNotEnded,
+    #[msg("Poll tally is already complete")]
+    TallyComplete,
+    #[msg("Account is not a vote on this poll")]
+    InvalidVoteAccount,
+    #[msg("This poll does not allow changing votes")]
+    VoteChangeNotAllowed,
+    #[msg("Ranked-choice tally must complete before finalizing")]
+    TallyIncomplete,
+    #[msg("Poll is already finalized")]
+    PollAlreadyFinalized,
+    #[msg("Poll has not been finalized")]
+    PollNotFinalized,
+    #[msg("Vote records are still open and the grace period has not passed")]
+    VoteRecordsOpen,
+    #[msg("Only the poll creator can perform this action")]
+    NotPollCreator,
+    #[msg("Ranked-choice polls must close their poll result account")]
+    PollResultRequired,
+    #[msg("Ranked-choice polls cannot be token-weighted")]
+    RankedPollCannotBeWeighted,
+    #[msg("Vote weight must be greater than zero")]
+    InvalidVoteWeight,
+    #[msg("Escrowed vote tokens are locked until the poll ends or the vote is revoked")]
+    VoteTokensLocked,
+    #[msg("Reveal window must end after the poll ends")]
+    InvalidRevealWindow,
+    #[msg("Secret ballot polls cannot be token-weighted or allow vote changes")]
+    SecretBallotConfigConflict,
+    #[msg("Poll is not in its reveal window")]
+    NotInRevealWindow,
+    #[msg("Vote has no hidden choice to reveal")]
+    NothingToReveal,
+    #[msg("Revealed choice does not match the commitment")]
+    CommitmentMismatch,
+    #[msg("Poll has not started yet")]
+    PollNotStarted,
+    #[msg("Poll start time must be before its end time")]
+    InvalidStartTime,
+    #[msg("Membership is too new to vote in this poll")]
+    MembershipTooNew,
+    #[msg("Minimum membership age cannot be negative")]
+    InvalidMinMembershipAge,
+    #[msg("Community is not accepting new members")]
+    CommunityClosed,
+    #[msg("Proposal poll must be an open single-choice poll with approve and reject options")]
+    InvalidProposalPoll,
+    #[msg("Approval threshold must be between 1 and 10000 basis points")]
+    InvalidThreshold,
+    #[msg("Proposal timelock cannot be negative")]
+    InvalidTimelock,
+    #[msg("Proposal has already been executed")]
+    ProposalAlreadyExecuted,
+    #[msg("Proposal did not pass")]
+    ProposalNotPassed,
+    #[msg("Proposal timelock has not elapsed")]
+    TimelockActive,
+    #[msg("Accounts do not match the proposal action")]
+    ProposalAccountsMismatch,
+    #[msg("Elections need profile options, a positive term and seats within the option count (1 if ranked)")]
+    InvalidElectionConfig,
+    #[msg("Poll is not an election")]
+    NotAnElection,
+    #[msg("Profile did not win this election")]
+    NotElectionWinner,
+    #[msg("Prizes need an open profile poll that has not received votes")]
+    InvalidPrizePoll,
+    #[msg("Prize amount must be greater than zero")]
+    InvalidPrizeAmount,
+    #[msg("Accounts do not match the prize or its winners")]
+    PrizeAccountsMismatch,
+    #[msg("Poll prize must be settled first")]
+    PrizeUnsettled,
+    #[msg("Bounty amount must be greater than zero")]
+    InvalidBountyAmount,
+    #[msg("Bounty expiry must be in the future and within the maximum duration")]
+    InvalidBountyExpiry,
+    #[msg("Bounty has expired")]
+    BountyExpired,
+    #[msg("Bounty has not expired yet")]
+    BountyNotExpired,
+    #[msg("Bounty cannot be awarded to the post author or a hidden comment")]
+    InvalidBountyRecipient,
+    #[msg("Post is not for sale")]
+    PostNotForSale,
+    #[msg("Cannot purchase your own post")]
+    CannotPurchaseOwnPost,
+    #[msg("Post price has changed")]
+    PostPriceChanged,
+    #[msg("Too many subscription tiers")]
+    TooManySubscriptionTiers,
+    #[msg("Subscription tiers need a positive price and period")]
+    InvalidSubscriptionTier,
+    #[msg("Subscription tier does not exist")]
+    SubscriptionTierNotFound,
+    #[msg("Subscription price has changed")]
+    SubscriptionPriceChanged,
+    #[msg("Cannot subscribe to yourself")]
+    CannotSubscribeToSelf,
+    #[msg("An active subscription to the creator is required")]
+    SubscriptionRequired,
+    #[msg("Cannot tip yourself")]
+    CannotTipSelf,
+    #[msg("Tip memo is too long")]
+    TipMemoTooLong,
+    #[msg("NFT name is too long")]
+    NftNameTooLong,
+    #[msg("NFT symbol is too long")]
+    NftSymbolTooLong,
+    #[msg("Seller fee cannot exceed 10000 basis points")]
+    InvalidSellerFee,
+    #[msg("Post has already been minted as an NFT")]
+    PostAlreadyMinted,
+    #[msg("Governance is not enabled for this community")]
+    GovernanceDisabled,
+    #[msg("Proposal is below the community's minimum quorum, threshold or timelock")]
+    BelowGovernanceMinimum,
+    #[msg("Election seat has already been claimed")]
+    SeatAlreadyClaimed,
+    #[msg("Account is already in the current layout")]
+    AccountAlreadyMigrated,
+    #[msg("Proposal must be executed or rejected before it can be closed")]
+    ProposalNotSettled,
 }
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::state::{PollMode, PollOptionKind, PostScope, ReportOutcome, ReportReason, ReportTarget};
 
 // ============= PROFILE EVENTS =============
 #[event]
@@ -30,6 +31,51 @@ pub struct UserUnfollowed {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct FollowRequested {
+    pub follower: Pubkey,
+    pub followed: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FollowApproved {
+    pub follower: Pubkey,
+    pub followed: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FollowRejected {
+    pub follower: Pubkey,
+    pub followed: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UserBlocked {
+    pub blocker: Pubkey,
+    pub blocked: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UserUnblocked {
+    pub blocker: Pubkey,
+    pub unblocked: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProfileTipped {
+    pub profile: Pubkey,
+    pub tipper: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub memo: Option<String>,
+    pub timestamp: i64,
+}
+
 // ============= COMMUNITY EVENTS =============
 #[event]
 pub struct CommunityCreated {
@@ -53,6 +99,38 @@ pub struct CommunityLeft {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct ModeratorAdded {
+    pub community: Pubkey,
+    pub moderator: Pubkey,
+    pub added_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ModeratorRemoved {
+    pub community: Pubkey,
+    pub moderator: Pubkey,
+    pub removed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ModeratorElected {
+    pub community: Pubkey,
+    pub moderator: Pubkey,
+    pub poll: Pubkey,
+    pub term_ends_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CommunityUpdated {
+    pub community: Pubkey,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
 // ============= POST EVENTS =============
 #[event]
 pub struct PostCreated {
@@ -61,6 +139,7 @@ pub struct PostCreated {
     pub post_id: u64,
     pub author: Option<Pubkey>,
     pub is_anonymous: bool,
+    pub scope: PostScope,
     pub timestamp: i64,
 }
 
@@ -93,6 +172,123 @@ pub struct PostTipped {
     pub tipper: Pubkey,
     pub recipient: Pubkey,
     pub amount: u64,
+    pub memo: Option<String>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CommentTipped {
+    pub comment: Pubkey,
+    pub post: Pubkey,
+    pub tipper: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub memo: Option<String>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PostReposted {
+    pub repost: Pubkey,
+    pub original_post: Pubkey,
+    pub reposter: Pubkey,
+    pub community: Option<Pubkey>,
+    pub is_quote: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PostLocked {
+    pub post: Pubkey,
+    pub locked_by: Pubkey,
+    pub locked_until: Option<i64>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PostUnlocked {
+    pub post: Pubkey,
+    pub unlocked_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// `target` is the community or profile the post is pinned to
+#[event]
+pub struct PostPinned {
+    pub post: Pubkey,
+    pub target: Pubkey,
+    pub pinned_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PostUnpinned {
+    pub post: Pubkey,
+    pub target: Pubkey,
+    pub unpinned_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BountyCreated {
+    pub bounty: Pubkey,
+    pub post: Pubkey,
+    pub author: Pubkey,
+    pub amount: u64,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BountyAwarded {
+    pub bounty: Pubkey,
+    pub post: Pubkey,
+    pub comment: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BountyRefunded {
+    pub bounty: Pubkey,
+    pub post: Pubkey,
+    pub author: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PostPriceSet {
+    pub post: Pubkey,
+    pub author: Pubkey,
+    pub price_lamports: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PostPurchased {
+    pub post: Pubkey,
+    pub unlock: Pubkey,
+    pub buyer: Pubkey,
+    pub author: Pubkey,
+    pub price_lamports: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PostNftMinted {
+    pub post: Pubkey,
+    pub mint: Pubkey,
+    pub author: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PostAccessSet {
+    pub post: Pubkey,
+    pub author: Pubkey,
+    pub subscribers_only: bool,
     pub timestamp: i64,
 }
 
@@ -103,6 +299,16 @@ pub struct PollCreated {
     pub community: Pubkey,
     pub poll_id: u64,
     pub creator: Pubkey,
+    pub option_kind: PollOptionKind,
+    pub option_count: u8,
+    pub mode: PollMode,
+    pub max_selections: u8,
+    pub weight_mint: Option<Pubkey>,
+    pub reveal_end_time: Option<i64>,
+    pub start_time: i64,
+    pub quorum: u32,
+    pub min_membership_age: i64,
+    pub election_seats: u8,
     pub end_time: i64,
     pub timestamp: i64,
 }
@@ -112,5 +318,182 @@ pub struct PollVoted {
     pub poll: Pubkey,
     pub voter: Pubkey,
     pub option_index: u8,
+    pub selections: u16,
+    pub weight: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoteCommitted {
+    pub poll: Pubkey,
+    pub voter: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoteChanged {
+    pub poll: Pubkey,
+    pub voter: Pubkey,
+    pub option_index: u8,
+    pub selections: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoteRevoked {
+    pub poll: Pubkey,
+    pub voter: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoteTokensWithdrawn {
+    pub poll: Pubkey,
+    pub voter: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PollFinalized {
+    pub poll: Pubkey,
+    pub winners: u16,
+    pub total_voters: u32,
+    pub quorum_met: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PollClosed {
+    pub poll: Pubkey,
+    pub closed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PrizeFunded {
+    pub poll: Pubkey,
+    pub prize: Pubkey,
+    pub mint: Option<Pubkey>,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PrizeSettled {
+    pub poll: Pubkey,
+    pub prize: Pubkey,
+    pub winners: Vec<Pubkey>,
+    pub amount_per_winner: u64,
+    pub refunded: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PollRoundTallied {
+    pub poll: Pubkey,
+    pub round: u8,
+    pub counts: Vec<u32>,
+    pub eliminated: Option<u8>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PollTallied {
+    pub poll: Pubkey,
+    pub winner: Option<u8>,
+    pub rounds: u8,
+    pub timestamp: i64,
+}
+
+// ============= MODERATION EVENTS =============
+#[event]
+pub struct ContentReported {
+    pub report: Pubkey,
+    pub community: Pubkey,
+    pub target: Pubkey,
+    pub target_kind: ReportTarget,
+    pub reporter: Pubkey,
+    pub reason: ReportReason,
+    pub reports_count: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ContentHidden {
+    pub community: Pubkey,
+    pub target: Pubkey,
+    pub target_kind: ReportTarget,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReportResolved {
+    pub report: Pubkey,
+    pub target: Pubkey,
+    pub outcome: ReportOutcome,
+    pub resolved_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UserBanned {
+    pub community: Pubkey,
+    pub user: Pubkey,
+    pub banned_by: Pubkey,
+    pub timestamp: i64,
+}
+
+// ============= GOVERNANCE EVENTS =============
+#[event]
+pub struct ProposalCreated {
+    pub proposal: Pubkey,
+    pub poll: Pubkey,
+    pub community: Pubkey,
+    pub proposer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalExecuted {
+    pub proposal: Pubkey,
+    pub community: Pubkey,
+    pub executed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalClosed {
+    pub proposal: Pubkey,
+    pub community: Pubkey,
+    pub closed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+// ============= SUBSCRIPTION EVENTS =============
+#[event]
+pub struct SubscriptionTiersSet {
+    pub profile: Pubkey,
+    pub owner: Pubkey,
+    pub tier_count: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct Subscribed {
+    pub subscription: Pubkey,
+    pub creator: Pubkey,
+    pub subscriber: Pubkey,
+    pub tier: u8,
+    pub price_lamports: u64,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SubscriptionCancelled {
+    pub subscription: Pubkey,
+    pub creator: Pubkey,
+    pub subscriber: Pubkey,
     pub timestamp: i64,
 }
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// Grant moderator rights to a community member
+/// 
+/// Validation:
+/// - Only the community creator can add moderators
+/// - Target user must be a member of the community
+/// - User cannot be added as moderator twice
+/// 
+/// Events: ModeratorAdded
+#[derive(Accounts)]
+pub struct AddModerator<'info> {
+    #[account(
+        has_one = creator @ SocialError::NotCommunityCreator
+    )]
+    pub community: Account<'info, CommunityAccount>,
+    
+    #[account(
+        has_one = community @ SocialError::NotCommunityMember
+    )]
+    pub membership: Account<'info, MembershipAccount>,
+    
+    #[account(
+        init,
+        payer = creator,
+        space = MODERATOR_SIZE,
+        seeds = [MODERATOR_SEED, community.key().as_ref(), membership.user.as_ref()],
+        bump
+    )]
+    pub moderator: Account<'info, ModeratorAccount>,
+    
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn add_moderator(ctx: Context<AddModerator>) -> Result<()> {
+    let moderator = &mut ctx.accounts.moderator;
+    let clock = Clock::get()?;
+    
+    moderator.community = ctx.accounts.community.key();
+    moderator.user = ctx.accounts.membership.user;
+    moderator.added_by = ctx.accounts.creator.key();
+    moderator.added_at = clock.unix_timestamp;
+    moderator.term_ends_at = None;
+    
+    emit!(ModeratorAdded {
+        community: moderator.community,
+        moderator: moderator.user,
+        added_by: moderator.added_by,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
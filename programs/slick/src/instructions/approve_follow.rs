@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// Approve a pending follow request
+/// 
+/// The request is converted into a FollowAccount. The approver pays for the
+/// follow account and receives the request's rent, so approving costs nothing
+/// net; the follower reclaims the follow rent when unfollowing.
+/// 
+/// Validation:
+/// - Only the followed profile's owner can approve
+/// - Request must exist
+/// - Approver must not have blocked the requester
+/// 
+/// Events: FollowApproved, UserFollowed
+#[derive(Accounts)]
+pub struct ApproveFollow<'info> {
+    #[account(
+        mut,
+        seeds = [PROFILE_SEED, owner.key().as_ref()],
+        bump,
+        has_one = owner
+    )]
+    pub followed_profile: Account<'info, ProfileAccount>,
+    
+    pub follower_profile: Account<'info, ProfileAccount>,
+    
+    /// CHECK: Block PDA from followed to follower, must not exist
+    #[account(
+        seeds = [BLOCK_SEED, followed_profile.key().as_ref(), follower_profile.key().as_ref()],
+        bump,
+        constraint = block.data_is_empty() @ SocialError::BlockedByUser
+    )]
+    pub block: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        close = owner,
+        seeds = [FOLLOW_REQUEST_SEED, follower_profile.key().as_ref(), followed_profile.key().as_ref()],
+        bump
+    )]
+    pub follow_request: Account<'info, FollowRequestAccount>,
+    
+    #[account(
+        init,
+        payer = owner,
+        space = FOLLOW_SIZE,
+        seeds = [FOLLOW_SEED, follower_profile.key().as_ref(), followed_profile.key().as_ref()],
+        bump
+    )]
+    pub follow: Account<'info, FollowAccount>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn approve_follow(ctx: Context<ApproveFollow>) -> Result<()> {
+    let follow = &mut ctx.accounts.follow;
+    let clock = Clock::get()?;
+    
+    follow.follower = ctx.accounts.follower_profile.key();
+    follow.followed = ctx.accounts.followed_profile.key();
+    follow.followed_at = clock.unix_timestamp;
+    
+    // Update counts
+    ctx.accounts.followed_profile.follower_count = ctx.accounts.followed_profile
+        .follower_count
+        .checked_add(1)
+        .ok_or(SocialError::ArithmeticOverflow)?;
+    
+    emit!(FollowApproved {
+        follower: follow.follower,
+        followed: follow.followed,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    emit!(UserFollowed {
+        follower: follow.follower,
+        followed: follow.followed,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// Award a post's bounty to a commenter
+/// 
+/// Pays the escrowed lamports to the comment's author and closes the bounty,
+/// returning its rent to the post author.
+/// 
+/// Validation:
+/// - Signer must be the post author
+/// - Bounty must not have expired
+/// - Comment must be on the post, not hidden, and not by the post author
+/// 
+/// Events: BountyAwarded
+#[derive(Accounts)]
+pub struct AwardBounty<'info> {
+    #[account(mut)]
+    pub post: Account<'info, PostAccount>,
+    
+    #[account(
+        mut,
+        close = author,
+        has_one = post,
+        has_one = author @ SocialError::NotPostAuthor,
+        seeds = [BOUNTY_SEED, post.key().as_ref()],
+        bump
+    )]
+    pub bounty: Account<'info, BountyAccount>,
+    
+    #[account(
+        constraint = comment.post == post.key() @ SocialError::InvalidBountyRecipient
+    )]
+    pub comment: Account<'info, CommentAccount>,
+    
+    /// CHECK: Comment author who receives the bounty
+    #[account(
+        mut,
+        address = comment.commenter @ SocialError::InvalidBountyRecipient
+    )]
+    pub recipient: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub author: Signer<'info>,
+}
+
+pub fn award_bounty(ctx: Context<AwardBounty>) -> Result<()> {
+    let bounty = &ctx.accounts.bounty;
+    let clock = Clock::get()?;
+    
+    require!(clock.unix_timestamp < bounty.expires_at, SocialError::BountyExpired);
+    require!(
+        !ctx.accounts.comment.hidden && ctx.accounts.recipient.key() != bounty.author,
+        SocialError::InvalidBountyRecipient
+    );
+    
+    // Pay out of the bounty account; its rent returns to the author on close
+    let amount = bounty.amount;
+    **bounty.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.recipient.try_borrow_mut_lamports()? += amount;
+    
+    emit!(BountyAwarded {
+        bounty: bounty.key(),
+        post: bounty.post,
+        comment: ctx.accounts.comment.key(),
+        recipient: ctx.accounts.recipient.key(),
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    ctx.accounts.post.bounty_lamports = 0;
+    
+    Ok(())
+}
@@ -0,0 +1,177 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*, utils::*};
+
+/// Block another user
+/// 
+/// Any follow relationship between the two users is torn down in both
+/// directions and follower counts are adjusted, and pending follow requests
+/// between them are withdrawn. Each closed follow or request account refunds
+/// its rent to the wallet that paid for it.
+/// 
+/// Validation:
+/// - Cannot block yourself
+/// - Cannot block the same user twice
+/// 
+/// Events: UserBlocked, UserUnfollowed (for each removed follow),
+/// FollowRejected (for each withdrawn request)
+#[derive(Accounts)]
+pub struct BlockUser<'info> {
+    #[account(
+        mut,
+        seeds = [PROFILE_SEED, owner.key().as_ref()],
+        bump,
+        has_one = owner
+    )]
+    pub blocker_profile: Account<'info, ProfileAccount>,
+    
+    #[account(mut)]
+    pub blocked_profile: Account<'info, ProfileAccount>,
+    
+    #[account(
+        init,
+        payer = owner,
+        space = BLOCK_SIZE,
+        seeds = [BLOCK_SEED, blocker_profile.key().as_ref(), blocked_profile.key().as_ref()],
+        bump
+    )]
+    pub block: Account<'info, BlockAccount>,
+    
+    /// CHECK: Follow PDA from blocker to blocked, closed if it exists
+    #[account(
+        mut,
+        seeds = [FOLLOW_SEED, blocker_profile.key().as_ref(), blocked_profile.key().as_ref()],
+        bump
+    )]
+    pub outgoing_follow: UncheckedAccount<'info>,
+    
+    /// CHECK: Follow PDA from blocked to blocker, closed if it exists
+    #[account(
+        mut,
+        seeds = [FOLLOW_SEED, blocked_profile.key().as_ref(), blocker_profile.key().as_ref()],
+        bump
+    )]
+    pub incoming_follow: UncheckedAccount<'info>,
+    
+    /// CHECK: Follow request PDA from blocker to blocked, closed if it exists
+    #[account(
+        mut,
+        seeds = [FOLLOW_REQUEST_SEED, blocker_profile.key().as_ref(), blocked_profile.key().as_ref()],
+        bump
+    )]
+    pub outgoing_follow_request: UncheckedAccount<'info>,
+    
+    /// CHECK: Follow request PDA from blocked to blocker, closed if it exists
+    #[account(
+        mut,
+        seeds = [FOLLOW_REQUEST_SEED, blocked_profile.key().as_ref(), blocker_profile.key().as_ref()],
+        bump
+    )]
+    pub incoming_follow_request: UncheckedAccount<'info>,
+    
+    /// CHECK: Wallet of the blocked user, receives rent from their closed
+    /// follow and follow request
+    #[account(
+        mut,
+        address = blocked_profile.owner
+    )]
+    pub blocked_owner: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn block_user(ctx: Context<BlockUser>) -> Result<()> {
+    // Check not blocking self
+    require!(
+        ctx.accounts.blocker_profile.key() != ctx.accounts.blocked_profile.key(),
+        SocialError::CannotBlockSelf
+    );
+    
+    let clock = Clock::get()?;
+    let blocker = ctx.accounts.blocker_profile.key();
+    let blocked = ctx.accounts.blocked_profile.key();
+    
+    let block = &mut ctx.accounts.block;
+    block.blocker = blocker;
+    block.blocked = blocked;
+    block.blocked_at = clock.unix_timestamp;
+    
+    // Tear down blocker -> blocked follow
+    if !ctx.accounts.outgoing_follow.data_is_empty() {
+        close_program_account(
+            &ctx.accounts.outgoing_follow,
+            &ctx.accounts.owner,
+            ctx.program_id,
+        )?;
+        
+        ctx.accounts.blocked_profile.follower_count = ctx.accounts.blocked_profile
+            .follower_count
+            .checked_sub(1)
+            .ok_or(SocialError::ArithmeticOverflow)?;
+        
+        emit!(UserUnfollowed {
+            follower: blocker,
+            unfollowed: blocked,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+    
+    // Tear down blocked -> blocker follow
+    if !ctx.accounts.incoming_follow.data_is_empty() {
+        close_program_account(
+            &ctx.accounts.incoming_follow,
+            &ctx.accounts.blocked_owner,
+            ctx.program_id,
+        )?;
+        
+        ctx.accounts.blocker_profile.follower_count = ctx.accounts.blocker_profile
+            .follower_count
+            .checked_sub(1)
+            .ok_or(SocialError::ArithmeticOverflow)?;
+        
+        emit!(UserUnfollowed {
+            follower: blocked,
+            unfollowed: blocker,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+    
+    // Withdraw pending follow requests in both directions
+    if !ctx.accounts.outgoing_follow_request.data_is_empty() {
+        close_program_account(
+            &ctx.accounts.outgoing_follow_request,
+            &ctx.accounts.owner,
+            ctx.program_id,
+        )?;
+        
+        emit!(FollowRejected {
+            follower: blocker,
+            followed: blocked,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+    
+    if !ctx.accounts.incoming_follow_request.data_is_empty() {
+        close_program_account(
+            &ctx.accounts.incoming_follow_request,
+            &ctx.accounts.blocked_owner,
+            ctx.program_id,
+        )?;
+        
+        emit!(FollowRejected {
+            follower: blocked,
+            followed: blocker,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+    
+    emit!(UserBlocked {
+        blocker,
+        blocked,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
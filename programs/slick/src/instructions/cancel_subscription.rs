@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, events::*, state::*};
+
+/// Cancel a subscription
+/// 
+/// Closes the subscription record and refunds its rent. Access ends
+/// immediately and paid periods are not refunded; to keep access until
+/// expiry, simply stop renewing.
+/// 
+/// Validation:
+/// - Signer must be the subscriber
+/// 
+/// Events: SubscriptionCancelled
+#[derive(Accounts)]
+pub struct CancelSubscription<'info> {
+    #[account(
+        mut,
+        close = subscriber,
+        has_one = subscriber,
+        seeds = [SUBSCRIPTION_SEED, subscription.creator.as_ref(), subscriber.key().as_ref()],
+        bump
+    )]
+    pub subscription: Account<'info, SubscriptionAccount>,
+    
+    #[account(mut)]
+    pub subscriber: Signer<'info>,
+}
+
+pub fn cancel_subscription(ctx: Context<CancelSubscription>) -> Result<()> {
+    let subscription = &ctx.accounts.subscription;
+    let clock = Clock::get()?;
+    
+    emit!(SubscriptionCancelled {
+        subscription: subscription.key(),
+        creator: subscription.creator,
+        subscriber: subscription.subscriber,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// Change an existing poll vote
+/// 
+/// Replaces the voter's selections in one step: the old options lose a vote
+/// and the new ones gain it. On ranked-choice polls `option_indices` is the
+/// new ranking.
+/// 
+/// Validation:
+/// - Poll must allow vote changes
+/// - Poll must not have ended
+/// - New selections must be valid for the poll mode
+/// 
+/// Events: VoteChanged
+#[derive(Accounts)]
+pub struct ChangeVote<'info> {
+    #[account(mut)]
+    pub poll: Account<'info, PollAccount>,
+    
+    #[account(
+        mut,
+        seeds = [VOTE_SEED, poll.key().as_ref(), voter.key().as_ref()],
+        bump,
+        has_one = poll,
+        has_one = voter
+    )]
+    pub vote: Account<'info, VoteAccount>,
+    
+    pub voter: Signer<'info>,
+}
+
+pub fn change_vote(ctx: Context<ChangeVote>, option_indices: Vec<u8>) -> Result<()> {
+    let poll = &mut ctx.accounts.poll;
+    let vote = &mut ctx.accounts.vote;
+    let clock = Clock::get()?;
+    
+    require!(poll.allow_vote_change, SocialError::VoteChangeNotAllowed);
+    
+    // Check poll has not ended
+    require!(
+        clock.unix_timestamp < poll.end_time,
+        SocialError::PollEnded
+    );
+    
+    // Validate new option indices against the poll mode
+    let selections = poll.selection_mask(&option_indices)?;
+    let option_index = option_indices[0];
+    
+    // Move the vote from the old options to the new ones
+    poll.remove_votes(vote.counted_options(), vote.weight)?;
+    
+    vote.option_index = option_index;
+    vote.selections = selections;
+    vote.voted_at = clock.unix_timestamp;
+    if poll.mode == PollMode::RankedChoice {
+        vote.ranking = option_indices;
+    }
+    
+    poll.add_votes(vote.counted_options(), vote.weight)?;
+    
+    emit!(VoteChanged {
+        poll: poll.key(),
+        voter: ctx.accounts.voter.key(),
+        option_index,
+        selections,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
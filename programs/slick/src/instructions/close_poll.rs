@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*, utils::*};
+
+/// Close a finalized poll and reclaim its rent
+/// 
+/// Ranked-choice polls also close their poll result account if a tally was
+/// started. Polls that missed quorum may never have started one, so the
+/// result address is passed either way and closed only if it exists.
+/// 
+/// Validation:
+/// - Signer must be the poll creator
+/// - Poll must be finalized
+/// - Any prize must have been settled
+/// - Ranked-choice polls must pass their poll result address
+/// - All vote records must be closed, or the grace period after
+///   end_time (or the reveal window) must have passed
+/// 
+/// Events: PollClosed
+#[derive(Accounts)]
+pub struct ClosePoll<'info> {
+    #[account(
+        mut,
+        close = creator,
+        constraint = poll.created_by == creator.key() @ SocialError::NotPollCreator
+    )]
+    pub poll: Account<'info, PollAccount>,
+    
+    /// CHECK: Poll result PDA, closed if a tally was started
+    #[account(
+        mut,
+        seeds = [POLL_RESULT_SEED, poll.key().as_ref()],
+        bump
+    )]
+    pub poll_result: Option<UncheckedAccount<'info>>,
+    
+    #[account(mut)]
+    pub creator: Signer<'info>,
+}
+
+pub fn close_poll(ctx: Context<ClosePoll>) -> Result<()> {
+    let poll = &ctx.accounts.poll;
+    let clock = Clock::get()?;
+    
+    require!(poll.is_finalized(), SocialError::PollNotFinalized);
+    require!(!poll.has_prize, SocialError::PrizeUnsettled);
+    require!(
+        poll.mode != PollMode::RankedChoice || ctx.accounts.poll_result.is_some(),
+        SocialError::PollResultRequired
+    );
+    
+    let grace_ends_at = poll.results_at()
+        .checked_add(POLL_CLOSE_GRACE_PERIOD)
+        .ok_or(SocialError::ArithmeticOverflow)?;
+    require!(
+        poll.open_vote_records == 0 || clock.unix_timestamp >= grace_ends_at,
+        SocialError::VoteRecordsOpen
+    );
+    
+    if let Some(poll_result) = ctx.accounts.poll_result.as_ref() {
+        if !poll_result.data_is_empty() {
+            close_program_account(poll_result, &ctx.accounts.creator, ctx.program_id)?;
+        }
+    }
+    
+    emit!(PollClosed {
+        poll: poll.key(),
+        closed_by: ctx.accounts.creator.key(),
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
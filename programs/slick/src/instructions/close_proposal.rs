@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::{errors::*, events::*, state::*};
+
+/// Close a settled governance proposal and reclaim its rent
+/// 
+/// A proposal is settled once it has been executed, its poll was finalized
+/// without passing, or its poll has already been closed. Passed proposals
+/// stay open until executed.
+/// 
+/// Validation:
+/// - Signer must be the proposer
+/// - Proposal must be settled
+/// 
+/// Events: ProposalClosed
+#[derive(Accounts)]
+pub struct CloseProposal<'info> {
+    /// CHECK: The proposal's poll; a PollAccount or, once closed, an empty account
+    #[account(address = proposal.poll)]
+    pub poll: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        close = proposer,
+        has_one = proposer
+    )]
+    pub proposal: Account<'info, ProposalAccount>,
+    
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+}
+
+pub fn close_proposal(ctx: Context<CloseProposal>) -> Result<()> {
+    let proposal = &ctx.accounts.proposal;
+    let poll_info = &ctx.accounts.poll;
+    let clock = Clock::get()?;
+    
+    let settled = proposal.executed_at.is_some() || poll_info.data_is_empty() || {
+        require_keys_eq!(*poll_info.owner, *ctx.program_id, ErrorCode::AccountOwnedByWrongProgram);
+        let poll = PollAccount::try_deserialize(&mut &poll_info.data.borrow()[..])?;
+        poll.is_finalized() && !proposal.passed(&poll)
+    };
+    require!(settled, SocialError::ProposalNotSettled);
+    
+    emit!(ProposalClosed {
+        proposal: proposal.key(),
+        community: proposal.community,
+        closed_by: ctx.accounts.proposer.key(),
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::{errors::*, state::*};
+
+/// Close a vote record and reclaim its rent
+/// 
+/// Allowed once the poll is finalized, or after the poll account itself has
+/// been closed.
+/// 
+/// Validation:
+/// - Signer must be the voter
+/// - Poll must be finalized or closed
+#[derive(Accounts)]
+pub struct CloseVoteRecord<'info> {
+    /// CHECK: The vote's poll; a finalized PollAccount or, once closed, an empty account
+    #[account(mut, address = vote.poll)]
+    pub poll: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        close = voter,
+        has_one = voter
+    )]
+    pub vote: Account<'info, VoteAccount>,
+    
+    #[account(mut)]
+    pub voter: Signer<'info>,
+}
+
+pub fn close_vote_record(ctx: Context<CloseVoteRecord>) -> Result<()> {
+    let poll_info = &ctx.accounts.poll;
+    
+    // Nothing left to update once the poll is closed
+    if poll_info.data_is_empty() {
+        return Ok(());
+    }
+    
+    require_keys_eq!(*poll_info.owner, *ctx.program_id, SocialError::PollNotFinalized);
+    let mut poll = PollAccount::try_deserialize(&mut &poll_info.data.borrow()[..])?;
+    require!(poll.is_finalized(), SocialError::PollNotFinalized);
+    
+    poll.open_vote_records = poll.open_vote_records.saturating_sub(1);
+    poll.try_serialize(&mut &mut poll_info.data.borrow_mut()[..])?;
+    
+    Ok(())
+}
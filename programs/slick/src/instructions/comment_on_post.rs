@@ -1,11 +1,15 @@
 use anchor_lang::prelude::*;
-use crate::{constants::*, errors::*, events::*, state::*};
+use crate::{constants::*, errors::*, events::*, state::*, utils::*};
 
 /// Comment on a post
 /// 
 /// Validation:
-/// - User must be a member of the community
+/// - User must be a member of the community (community posts only)
+/// - User must not be banned from the post's community
 /// - Content URI must be within length limits
+/// - Post must not be locked
+/// - Post author must not have blocked the user
+/// - Subscriber-only posts need an active subscription to the author
 /// 
 /// Events: CommentCreated
 #[derive(Accounts)]
@@ -17,7 +21,15 @@ pub struct CommentOnPost<'info> {
         seeds = [MEMBERSHIP_SEED, post.community.as_ref(), commenter.key().as_ref()],
         bump
     )]
-    pub membership: Account<'info, MembershipAccount>,
+    pub membership: Option<Account<'info, MembershipAccount>>,
+    
+    /// CHECK: Ban record PDA, must not exist for the user to comment
+    #[account(
+        seeds = [BAN_SEED, post.community.as_ref(), commenter.key().as_ref()],
+        bump,
+        constraint = ban.data_is_empty() @ SocialError::UserBanned
+    )]
+    pub ban: UncheckedAccount<'info>,
     
     #[account(
         init,
@@ -28,6 +40,12 @@ pub struct CommentOnPost<'info> {
     )]
     pub comment: Account<'info, CommentAccount>,
     
+    /// CHECK: Block PDA from the post author to the commenter, verified in handler
+    pub block: Option<UncheckedAccount<'info>>,
+    
+    /// Commenter's subscription to the post author, required on subscriber-only posts
+    pub subscription: Option<Account<'info, SubscriptionAccount>>,
+    
     #[account(mut)]
     pub commenter: Signer<'info>,
     
@@ -45,10 +63,44 @@ pub fn comment_on_post(
         SocialError::ContentUriTooLong
     );
     
+    // Community posts can only be commented on by members
+    if ctx.accounts.post.scope == PostScope::Community {
+        require!(
+            ctx.accounts.membership.is_some(),
+            SocialError::NotCommunityMember
+        );
+    }
+    
     let post = &mut ctx.accounts.post;
     let comment = &mut ctx.accounts.comment;
     let clock = Clock::get()?;
     
+    // Check post is not locked
+    require!(
+        !post.is_locked(clock.unix_timestamp),
+        SocialError::PostLocked
+    );
+    
+    // Check the author has not blocked the commenter
+    if let Some(author) = post.author {
+        require_not_blocked(
+            ctx.accounts.block.as_deref(),
+            &author,
+            &ctx.accounts.commenter.key(),
+            ctx.program_id,
+        )?;
+        
+        // Subscriber-only posts are open to the author and their subscribers
+        if post.subscribers_only && author != ctx.accounts.commenter.key() {
+            require_active_subscription(
+                ctx.accounts.subscription.as_deref(),
+                &author,
+                &ctx.accounts.commenter.key(),
+                clock.unix_timestamp,
+            )?;
+        }
+    }
+    
     let comment_id = post.comments_count;
     
     comment.post = post.key();
@@ -57,6 +109,10 @@ pub fn comment_on_post(
     comment.content_uri = content_uri;
     comment.content_hash = content_hash;
     comment.created_at = clock.unix_timestamp;
+    comment.reports_count = 0;
+    comment.hidden = false;
+    comment.total_tip_lamports = 0;
+    comment.removed = false;
     
     // Increment comment count
     post.comments_count = post.comments_count
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::{errors::*, events::*, instructions::VotePoll};
+
+/// Cast a secret ballot
+/// 
+/// Stores only a commitment to the voter's choice:
+/// `sha256(poll || voter || option_indices || salt)`. The choice is counted
+/// when revealed with `reveal_vote` after the poll ends; commitments never
+/// revealed are ignored.
+/// 
+/// Validation:
+/// - Poll must be a secret ballot poll
+/// - Voter must be a member of the community, for at least the poll's
+///   minimum membership age
+/// - Voter can only vote once per poll
+/// - Poll must have started and not ended
+/// 
+/// Events: VoteCommitted
+pub fn commit_vote(ctx: Context<VotePoll>, commitment: [u8; 32]) -> Result<()> {
+    let poll = &mut ctx.accounts.poll;
+    let vote = &mut ctx.accounts.vote;
+    let clock = Clock::get()?;
+    
+    require!(poll.reveal_end_time.is_some(), SocialError::PollModeMismatch);
+    
+    // Check voting is open to this member
+    poll.require_can_vote(&ctx.accounts.membership, clock.unix_timestamp)?;
+    
+    vote.poll = poll.key();
+    vote.voter = ctx.accounts.voter.key();
+    vote.voted_at = clock.unix_timestamp;
+    vote.weight = 1;
+    vote.commitment = Some(commitment);
+    
+    poll.unrevealed_votes = poll.unrevealed_votes
+        .checked_add(1)
+        .ok_or(SocialError::ArithmeticOverflow)?;
+    
+    emit!(VoteCommitted {
+        poll: poll.key(),
+        voter: vote.voter,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// Attach a bounty to a post
+/// 
+/// Escrows `amount` lamports in the post's bounty account. The author can
+/// award it to a commenter with `award_bounty` until `expires_at`, after
+/// which anyone can return it to the author with `refund_bounty`. Send in
+/// the same transaction as `create_post` to publish a question with its
+/// reward.
+/// 
+/// Validation:
+/// - Signer must be the post author (not anonymous)
+/// - Amount must be greater than zero
+/// - Expiry must be in the future, within MAX_BOUNTY_DURATION
+/// - One open bounty per post
+/// 
+/// Events: BountyCreated
+#[derive(Accounts)]
+pub struct CreateBounty<'info> {
+    #[account(
+        mut,
+        constraint = post.author == Some(author.key()) @ SocialError::NotPostAuthor
+    )]
+    pub post: Account<'info, PostAccount>,
+    
+    #[account(
+        init,
+        payer = author,
+        space = BOUNTY_SIZE,
+        seeds = [BOUNTY_SEED, post.key().as_ref()],
+        bump
+    )]
+    pub bounty: Account<'info, BountyAccount>,
+    
+    #[account(mut)]
+    pub author: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_bounty(ctx: Context<CreateBounty>, amount: u64, expires_at: i64) -> Result<()> {
+    let clock = Clock::get()?;
+    
+    require!(amount > 0, SocialError::InvalidBountyAmount);
+    
+    let max_expires_at = clock.unix_timestamp
+        .checked_add(MAX_BOUNTY_DURATION)
+        .ok_or(SocialError::ArithmeticOverflow)?;
+    require!(
+        expires_at > clock.unix_timestamp && expires_at <= max_expires_at,
+        SocialError::InvalidBountyExpiry
+    );
+    
+    // Escrow the lamports on top of the bounty account's rent
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.author.to_account_info(),
+                to: ctx.accounts.bounty.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    let post = &mut ctx.accounts.post;
+    let bounty = &mut ctx.accounts.bounty;
+    
+    bounty.post = post.key();
+    bounty.author = ctx.accounts.author.key();
+    bounty.amount = amount;
+    bounty.expires_at = expires_at;
+    bounty.created_at = clock.unix_timestamp;
+    
+    post.bounty_lamports = amount;
+    
+    emit!(BountyCreated {
+        bounty: bounty.key(),
+        post: post.key(),
+        author: bounty.author,
+        amount,
+        expires_at,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
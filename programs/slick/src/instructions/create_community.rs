@@ -63,6 +63,13 @@ pub fn create_community(
     community.post_counter = 0;
     community.poll_counter = 0;
     community.created_at = clock.unix_timestamp;
+    community.pinned_posts = Vec::new();
+    community.report_threshold = 0;
+    community.join_policy = JoinPolicy::Open;
+    community.join_fee = 0;
+    community.min_governance_quorum = 0;
+    community.min_governance_threshold_bps = 0;
+    community.min_governance_timelock = 0;
     
     // Initialize creator's membership
     membership.community = community.key();
@@ -3,13 +3,43 @@ use crate::{constants::*, errors::*, events::*, state::*};
 
 /// Create a poll in a community
 /// 
-/// Poll options are existing user profiles (Pubkeys).
+/// `config.mode` picks single-choice, multi-choice (up to
+/// `config.max_selections` options per voter), approval voting, or
+/// ranked-choice voting tallied by instant-runoff. `config.allow_vote_change`
+/// lets voters change or revoke their vote until the poll ends.
+/// `config.weight_mint` makes the poll token-weighted (see `vote_poll_weighted`).
+/// `config.reveal_end_time` makes it a secret ballot (see `commit_vote`).
+/// `config.start_time` delays voting, `config.quorum` sets the minimum number
+/// of voters for the result to stand, and `config.min_membership_age` keeps
+/// members who joined too recently from voting. `config.election_seats`
+/// makes the poll a moderator election (see `seat_elected_moderator`).
+/// 
+/// Profile polls take their options from `option_profiles` ("pick a person").
+/// Text and URI polls take them from `option_labels` ("pick an answer").
+/// 
+/// For profile polls, remaining accounts must list the profile account of
+/// every option in order. Listing `[profile, membership]` pairs instead also
+/// verifies that each option is a member of the community.
 /// 
 /// Validation:
 /// - Creator must be a member of the community
 /// - Question URI must be within length limits
+/// - Options must be supplied in the list matching `option_kind`
 /// - Must have at least 2 options and no more than MAX_POLL_OPTIONS
+/// - Options must be distinct
+/// - Labels must be within length limits
+/// - Multi-choice max selections must be between 1 and the option count
+/// - Ranked-choice polls cannot be token-weighted
+/// - Secret ballot reveal windows must end after end_time, and secret ballots
+///   cannot be token-weighted or allow vote changes
+/// - Option profiles must be real profiles (and members, if memberships are passed)
 /// - End time must be in the future
+/// - Start time (if any) must be before end time
+/// - Minimum membership age must not be negative
+/// - Elections need profile options, a positive term length, and no more
+///   seats than options (one seat on ranked-choice polls)
+/// - Elections need governance enabled and a quorum of at least the
+///   community's minimum governance quorum
 /// 
 /// Events: PollCreated
 #[derive(Accounts)]
@@ -41,7 +71,10 @@ pub struct CreatePoll<'info> {
 pub fn create_poll(
     ctx: Context<CreatePoll>,
     question_uri: String,
+    option_kind: PollOptionKind,
     option_profiles: Vec<Pubkey>,
+    option_labels: Vec<String>,
+    config: PollConfig,
     end_time: i64,
 ) -> Result<()> {
     // Validate question URI length
@@ -50,12 +83,100 @@ pub fn create_poll(
         SocialError::QuestionUriTooLong
     );
     
+    // Options must come from the list matching the option kind
+    let option_count = match option_kind {
+        PollOptionKind::Profile => {
+            require!(option_labels.is_empty(), SocialError::PollOptionKindMismatch);
+            for (i, profile) in option_profiles.iter().enumerate() {
+                require!(
+                    !option_profiles[..i].contains(profile),
+                    SocialError::DuplicatePollOption
+                );
+            }
+            option_profiles.len()
+        }
+        PollOptionKind::Text | PollOptionKind::Uri => {
+            require!(option_profiles.is_empty(), SocialError::PollOptionKindMismatch);
+            for (i, label) in option_labels.iter().enumerate() {
+                require!(
+                    label.len() <= MAX_POLL_OPTION_LABEL_LEN,
+                    SocialError::PollOptionLabelTooLong
+                );
+                require!(
+                    !option_labels[..i].contains(label),
+                    SocialError::DuplicatePollOption
+                );
+            }
+            option_labels.len()
+        }
+    };
+    
     // Validate number of options
     require!(
-        option_profiles.len() >= 2 && option_profiles.len() <= MAX_POLL_OPTIONS,
+        option_count >= MIN_POLL_OPTIONS,
+        SocialError::TooFewPollOptions
+    );
+    require!(
+        option_count <= MAX_POLL_OPTIONS,
         SocialError::TooManyPollOptions
     );
     
+    // Resolve how many options each voter may select
+    let max_selections = match config.mode {
+        PollMode::SingleChoice => 1,
+        PollMode::MultiChoice => {
+            require!(
+                config.max_selections >= 1 && (config.max_selections as usize) <= option_count,
+                SocialError::InvalidMaxSelections
+            );
+            config.max_selections
+        }
+        PollMode::Approval | PollMode::RankedChoice => option_count as u8,
+    };
+    
+    // Tallies count ballots, so ranked-choice polls stay one member, one vote
+    require!(
+        config.mode != PollMode::RankedChoice || config.weight_mint.is_none(),
+        SocialError::RankedPollCannotBeWeighted
+    );
+    
+    // Secret ballots reveal after end_time; weights and changes would leak
+    // or unsettle hidden choices
+    if let Some(reveal_end_time) = config.reveal_end_time {
+        require!(reveal_end_time > end_time, SocialError::InvalidRevealWindow);
+        require!(
+            config.weight_mint.is_none() && !config.allow_vote_change,
+            SocialError::SecretBallotConfigConflict
+        );
+    }
+    
+    // Elections seat winning profiles as moderators
+    if config.election_seats > 0 {
+        require!(
+            option_kind == PollOptionKind::Profile
+                && (config.election_seats as usize) <= option_count
+                && config.term_length > 0
+                && (config.mode != PollMode::RankedChoice || config.election_seats == 1),
+            SocialError::InvalidElectionConfig
+        );
+        
+        // Seats carry moderator powers, so they need the same turnout as
+        // governance proposals
+        let min_quorum = ctx.accounts.community.min_governance_quorum;
+        require!(min_quorum > 0, SocialError::GovernanceDisabled);
+        require!(config.quorum >= min_quorum, SocialError::BelowGovernanceMinimum);
+    }
+    
+    // Verify option profiles against the remaining accounts
+    if option_kind == PollOptionKind::Profile {
+        validate_option_profiles(
+            &option_profiles,
+            ctx.remaining_accounts,
+            &ctx.accounts.community.key(),
+            ctx.program_id,
+        )?;
+    }
+    
     let clock = Clock::get()?;
     
     // Validate end time is in the future
@@ -64,13 +185,23 @@ pub fn create_poll(
         SocialError::PollEnded
     );
     
+    // Voting opens at start_time, or immediately
+    let start_time = config.start_time
+        .unwrap_or(clock.unix_timestamp)
+        .max(clock.unix_timestamp);
+    require!(start_time < end_time, SocialError::InvalidStartTime);
+    require!(
+        config.min_membership_age >= 0,
+        SocialError::InvalidMinMembershipAge
+    );
+    
     let community = &mut ctx.accounts.community;
     let poll = &mut ctx.accounts.poll;
     
     let poll_id = community.poll_counter;
     
     // Initialize vote counts to zero for each option
-    let votes_per_option = vec![0u32; option_profiles.len()];
+    let votes_per_option = vec![0u32; option_count];
     
     poll.community = community.key();
     poll.poll_id = poll_id;
@@ -80,6 +211,27 @@ pub fn create_poll(
     poll.created_by = ctx.accounts.creator.key();
     poll.end_time = end_time;
     poll.created_at = clock.unix_timestamp;
+    poll.option_kind = option_kind;
+    poll.option_labels = option_labels;
+    poll.mode = config.mode;
+    poll.max_selections = max_selections;
+    poll.total_voters = 0;
+    poll.allow_vote_change = config.allow_vote_change;
+    poll.weight_mint = config.weight_mint;
+    poll.reveal_end_time = config.reveal_end_time;
+    poll.unrevealed_votes = 0;
+    poll.start_time = start_time;
+    poll.quorum = config.quorum;
+    poll.min_membership_age = config.min_membership_age;
+    poll.quorum_met = false;
+    poll.election_seats = config.election_seats;
+    poll.term_length = config.term_length;
+    poll.has_prize = false;
+    poll.seated_winners = 0;
+    poll.weighted_votes = match config.weight_mint {
+        Some(_) => vec![0u64; option_count],
+        None => Vec::new(),
+    };
     
     // Increment community poll counter
     community.poll_counter = community.poll_counter
@@ -91,9 +243,59 @@ pub fn create_poll(
         community: community.key(),
         poll_id,
         creator: poll.created_by,
+        option_kind,
+        option_count: option_count as u8,
+        mode: config.mode,
+        max_selections,
+        weight_mint: config.weight_mint,
+        reveal_end_time: config.reveal_end_time,
+        start_time,
+        quorum: config.quorum,
+        min_membership_age: config.min_membership_age,
+        election_seats: config.election_seats,
         end_time,
         timestamp: clock.unix_timestamp,
     });
     
     Ok(())
 }
+
+/// Checks that every option profile is a real ProfileAccount owned by this
+/// program and, when memberships are interleaved, a member of the community
+fn validate_option_profiles(
+    option_profiles: &[Pubkey],
+    remaining_accounts: &[AccountInfo],
+    community: &Pubkey,
+    program_id: &Pubkey,
+) -> Result<()> {
+    let with_membership = remaining_accounts.len() == option_profiles.len() * 2;
+    require!(
+        with_membership || remaining_accounts.len() == option_profiles.len(),
+        SocialError::OptionAccountsMismatch
+    );
+    let stride = if with_membership { 2 } else { 1 };
+    
+    for (option, accounts) in option_profiles.iter().zip(remaining_accounts.chunks(stride)) {
+        let profile_info = &accounts[0];
+        require_keys_eq!(profile_info.key(), *option, SocialError::OptionAccountsMismatch);
+        require_keys_eq!(*profile_info.owner, *program_id, SocialError::InvalidOptionProfile);
+        
+        // Checks the ProfileAccount discriminator
+        let profile = ProfileAccount::try_deserialize(&mut &profile_info.data.borrow()[..])
+            .map_err(|_| SocialError::InvalidOptionProfile)?;
+        
+        if with_membership {
+            let membership_info = &accounts[1];
+            require_keys_eq!(*membership_info.owner, *program_id, SocialError::NotCommunityMember);
+            
+            let membership = MembershipAccount::try_deserialize(&mut &membership_info.data.borrow()[..])
+                .map_err(|_| SocialError::NotCommunityMember)?;
+            require!(
+                membership.community == *community && membership.user == profile.owner,
+                SocialError::NotCommunityMember
+            );
+        }
+    }
+    
+    Ok(())
+}
@@ -5,6 +5,7 @@ use crate::{constants::*, errors::*, events::*, state::*};
 /// 
 /// Validation:
 /// - User must be a member of the community
+/// - User must not be banned from the community
 /// - Content URI must be within length limits
 /// - If anonymous: pseudonym is required
 /// - If not anonymous: author is set to signer
@@ -21,6 +22,14 @@ pub struct CreatePost<'info> {
     )]
     pub membership: Account<'info, MembershipAccount>,
     
+    /// CHECK: Ban record PDA, must not exist for the user to post
+    #[account(
+        seeds = [BAN_SEED, community.key().as_ref(), author.key().as_ref()],
+        bump,
+        constraint = ban.data_is_empty() @ SocialError::UserBanned
+    )]
+    pub ban: UncheckedAccount<'info>,
+    
     #[account(
         init,
         payer = author,
@@ -78,6 +87,18 @@ pub fn create_post(
     post.comments_count = 0;
     post.total_tip_lamports = 0;
     post.created_at = clock.unix_timestamp;
+    post.reposts_count = 0;
+    post.scope = PostScope::Community;
+    post.locked = false;
+    post.locked_until = None;
+    post.reports_count = 0;
+    post.hidden = false;
+    post.bounty_lamports = 0;
+    post.price_lamports = 0;
+    post.unlocks_count = 0;
+    post.subscribers_only = false;
+    post.nft_mint = None;
+    post.removed = false;
     
     // Increment community post counter
     community.post_counter = community.post_counter
@@ -90,6 +111,7 @@ pub fn create_post(
         post_id,
         author: post.author,
         is_anonymous,
+        scope: PostScope::Community,
         timestamp: clock.unix_timestamp,
     });
     
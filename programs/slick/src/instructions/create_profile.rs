@@ -49,6 +49,11 @@ pub fn create_profile(
     profile.follower_count = 0;
     profile.following_count = 0;
     profile.created_at = clock.unix_timestamp;
+    profile.post_counter = 0;
+    profile.pinned_posts = Vec::new();
+    profile.requires_approval = false;
+    profile.subscription_tiers = Vec::new();
+    profile.total_tips_received = 0;
     
     emit!(ProfileCreated {
         profile: profile.key(),
@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// Create a post on the author's own profile timeline
+/// 
+/// Profile posts are not tied to a community, so followers can build a feed
+/// from on-chain data. Likes, comments and tips use the regular post
+/// instructions without a membership account.
+/// 
+/// Validation:
+/// - Author must have a profile
+/// - Content URI must be within length limits
+/// 
+/// Events: PostCreated
+#[derive(Accounts)]
+pub struct CreateProfilePost<'info> {
+    #[account(
+        mut,
+        seeds = [PROFILE_SEED, author.key().as_ref()],
+        bump
+    )]
+    pub profile: Account<'info, ProfileAccount>,
+    
+    #[account(
+        init,
+        payer = author,
+        space = POST_SIZE,
+        seeds = [POST_SEED, profile.key().as_ref(), &profile.post_counter.to_le_bytes()],
+        bump
+    )]
+    pub post: Account<'info, PostAccount>,
+    
+    #[account(mut)]
+    pub author: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_profile_post(
+    ctx: Context<CreateProfilePost>,
+    content_uri: String,
+    content_hash: [u8; 32],
+) -> Result<()> {
+    // Validate content URI length
+    require!(
+        content_uri.len() <= MAX_CONTENT_URI_LEN,
+        SocialError::ContentUriTooLong
+    );
+    
+    let profile = &mut ctx.accounts.profile;
+    let post = &mut ctx.accounts.post;
+    let clock = Clock::get()?;
+    
+    let post_id = profile.post_counter;
+    
+    post.community = profile.key();
+    post.post_id = post_id;
+    post.content_uri = content_uri;
+    post.content_hash = content_hash;
+    post.author = Some(ctx.accounts.author.key());
+    post.pseudonym = None;
+    post.likes_count = 0;
+    post.comments_count = 0;
+    post.total_tip_lamports = 0;
+    post.created_at = clock.unix_timestamp;
+    post.reposts_count = 0;
+    post.scope = PostScope::Profile;
+    post.locked = false;
+    post.locked_until = None;
+    post.reports_count = 0;
+    post.hidden = false;
+    post.bounty_lamports = 0;
+    post.price_lamports = 0;
+    post.unlocks_count = 0;
+    post.subscribers_only = false;
+    post.nft_mint = None;
+    post.removed = false;
+    
+    // Increment profile post counter
+    profile.post_counter = profile.post_counter
+        .checked_add(1)
+        .ok_or(SocialError::ArithmeticOverflow)?;
+    
+    emit!(PostCreated {
+        post: post.key(),
+        community: profile.key(),
+        post_id,
+        author: post.author,
+        is_anonymous: false,
+        scope: PostScope::Profile,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
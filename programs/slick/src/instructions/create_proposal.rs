@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// Attach a governance action to a poll
+/// 
+/// The poll's first option approves the proposal and its second rejects it.
+/// Send in the same transaction as `create_poll`, before anyone votes.
+/// 
+/// Validation:
+/// - Community must have governance enabled by its creator
+/// - Proposer must be a member of the community and the poll's creator
+/// - Poll must be an open single-choice poll with exactly 2 options and no
+///   votes or committed ballots yet
+/// - Poll quorum, threshold and timelock must meet the community minimums
+/// - A poll carries at most one proposal
+/// - Threshold must be between 1 and 10000 basis points
+/// - Timelock must not be negative
+/// - Description URI (for description updates) must be within length limits
+/// 
+/// Events: ProposalCreated
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    pub community: Account<'info, CommunityAccount>,
+    
+    #[account(
+        seeds = [MEMBERSHIP_SEED, community.key().as_ref(), proposer.key().as_ref()],
+        bump
+    )]
+    pub membership: Account<'info, MembershipAccount>,
+    
+    #[account(
+        has_one = community,
+        constraint = poll.created_by == proposer.key() @ SocialError::NotPollCreator
+    )]
+    pub poll: Account<'info, PollAccount>,
+    
+    #[account(
+        init,
+        payer = proposer,
+        space = PROPOSAL_SIZE,
+        seeds = [PROPOSAL_SEED, poll.key().as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, ProposalAccount>,
+    
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_proposal(
+    ctx: Context<CreateProposal>,
+    action: ProposalAction,
+    threshold_bps: u16,
+    timelock: i64,
+) -> Result<()> {
+    let community = &ctx.accounts.community;
+    let poll = &ctx.accounts.poll;
+    let clock = Clock::get()?;
+    
+    require!(community.min_governance_quorum > 0, SocialError::GovernanceDisabled);
+    
+    // Approve / reject poll that is still open and has not been voted on
+    require!(
+        poll.mode == PollMode::SingleChoice
+            && poll.option_count() == 2
+            && clock.unix_timestamp < poll.end_time
+            && poll.total_voters == 0
+            && poll.unrevealed_votes == 0,
+        SocialError::InvalidProposalPoll
+    );
+    
+    require!(
+        threshold_bps > 0 && threshold_bps <= MAX_THRESHOLD_BPS,
+        SocialError::InvalidThreshold
+    );
+    require!(timelock >= 0, SocialError::InvalidTimelock);
+    require!(
+        poll.quorum >= community.min_governance_quorum
+            && threshold_bps >= community.min_governance_threshold_bps
+            && timelock >= community.min_governance_timelock,
+        SocialError::BelowGovernanceMinimum
+    );
+    
+    if let ProposalAction::UpdateDescription { description_uri } = &action {
+        require!(
+            description_uri.len() <= MAX_DESCRIPTION_URI_LEN,
+            SocialError::DescriptionUriTooLong
+        );
+    }
+    
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.poll = poll.key();
+    proposal.community = community.key();
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.action = action;
+    proposal.threshold_bps = threshold_bps;
+    proposal.timelock = timelock;
+    proposal.created_at = clock.unix_timestamp;
+    proposal.executed_at = None;
+    
+    emit!(ProposalCreated {
+        proposal: proposal.key(),
+        poll: proposal.poll,
+        community: proposal.community,
+        proposer: proposal.proposer,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
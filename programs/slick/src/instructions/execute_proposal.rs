@@ -0,0 +1,146 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// Apply a passed governance proposal to its community
+/// 
+/// Permissionless. Moderator actions take extra accounts:
+/// - AddModerator: `member_membership` and `new_moderator` (paid by the
+///   executor if new). An existing record, such as an elected moderator's,
+///   is overwritten with a permanent seat
+/// - RemoveModerator: `removed_moderator` (rent refunded to the executor)
+/// 
+/// Validation:
+/// - Proposal must not have been executed
+/// - Poll must be finalized with quorum and enough approving votes
+/// - Timelock after the end of voting must have elapsed
+/// - Accounts must match the proposal action
+/// 
+/// Events: ProposalExecuted, plus CommunityUpdated / ModeratorAdded / ModeratorRemoved
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    #[account(mut)]
+    pub community: Account<'info, CommunityAccount>,
+    
+    pub poll: Account<'info, PollAccount>,
+    
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, poll.key().as_ref()],
+        bump,
+        has_one = community,
+        has_one = poll
+    )]
+    pub proposal: Account<'info, ProposalAccount>,
+    
+    /// Membership of the user being made moderator
+    #[account(
+        seeds = [MEMBERSHIP_SEED, community.key().as_ref(), proposal.action.target_user().as_ref()],
+        bump
+    )]
+    pub member_membership: Option<Account<'info, MembershipAccount>>,
+    
+    #[account(
+        init_if_needed,
+        payer = executor,
+        space = MODERATOR_SIZE,
+        seeds = [MODERATOR_SEED, community.key().as_ref(), proposal.action.target_user().as_ref()],
+        bump
+    )]
+    pub new_moderator: Option<Account<'info, ModeratorAccount>>,
+    
+    #[account(
+        mut,
+        close = executor,
+        seeds = [MODERATOR_SEED, community.key().as_ref(), proposal.action.target_user().as_ref()],
+        bump
+    )]
+    pub removed_moderator: Option<Account<'info, ModeratorAccount>>,
+    
+    #[account(mut)]
+    pub executor: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+    let community = &mut ctx.accounts.community;
+    let poll = &ctx.accounts.poll;
+    let proposal = &mut ctx.accounts.proposal;
+    let clock = Clock::get()?;
+    
+    require!(proposal.executed_at.is_none(), SocialError::ProposalAlreadyExecuted);
+    require!(proposal.passed(poll), SocialError::ProposalNotPassed);
+    
+    let executable_at = poll.results_at()
+        .checked_add(proposal.timelock)
+        .ok_or(SocialError::ArithmeticOverflow)?;
+    require!(
+        clock.unix_timestamp >= executable_at,
+        SocialError::TimelockActive
+    );
+    
+    // Moderator accounts are only accepted for the matching action
+    let is_add = matches!(proposal.action, ProposalAction::AddModerator { .. });
+    let is_remove = matches!(proposal.action, ProposalAction::RemoveModerator { .. });
+    require!(
+        ctx.accounts.member_membership.is_some() == is_add
+            && ctx.accounts.new_moderator.is_some() == is_add
+            && ctx.accounts.removed_moderator.is_some() == is_remove,
+        SocialError::ProposalAccountsMismatch
+    );
+    
+    match &proposal.action {
+        ProposalAction::UpdateDescription { description_uri } => {
+            community.description_uri = description_uri.clone();
+        }
+        ProposalAction::SetJoinFee { join_fee } => {
+            community.join_fee = *join_fee;
+        }
+        ProposalAction::SetJoinPolicy { join_policy } => {
+            community.join_policy = *join_policy;
+        }
+        ProposalAction::AddModerator { user } => {
+            if let Some(moderator) = ctx.accounts.new_moderator.as_mut() {
+                moderator.community = community.key();
+                moderator.user = *user;
+                moderator.added_by = proposal.key();
+                moderator.added_at = clock.unix_timestamp;
+                moderator.term_ends_at = None;
+            }
+            
+            emit!(ModeratorAdded {
+                community: community.key(),
+                moderator: *user,
+                added_by: proposal.key(),
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        ProposalAction::RemoveModerator { user } => {
+            emit!(ModeratorRemoved {
+                community: community.key(),
+                moderator: *user,
+                removed_by: proposal.key(),
+                timestamp: clock.unix_timestamp,
+            });
+        }
+    }
+    
+    if !is_add && !is_remove {
+        emit!(CommunityUpdated {
+            community: community.key(),
+            updated_by: proposal.key(),
+            timestamp: clock.unix_timestamp,
+        });
+    }
+    
+    proposal.executed_at = Some(clock.unix_timestamp);
+    
+    emit!(ProposalExecuted {
+        proposal: proposal.key(),
+        community: community.key(),
+        executed_by: ctx.accounts.executor.key(),
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
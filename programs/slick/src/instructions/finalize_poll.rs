@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// Finalize a poll's results
+/// 
+/// Permissionless. Snapshots the winning options into the poll and marks it
+/// closed, after which voters may reclaim rent with `close_vote_record`.
+/// Ranked-choice polls take their winner from the completed tally; other
+/// polls are won by the option(s) with the most votes. Unrevealed secret
+/// ballots were never counted and do not affect the result. A poll that
+/// misses its quorum is finalized with no winners. Elections are won by the
+/// `election_seats` options with the most votes, who can then be seated with
+/// `seat_elected_moderator`.
+/// 
+/// Validation:
+/// - Poll must have ended, including any reveal window
+/// - Poll must not already be finalized
+/// - Ranked-choice polls must pass their completed poll result
+/// 
+/// Events: PollFinalized
+#[derive(Accounts)]
+pub struct FinalizePoll<'info> {
+    #[account(mut)]
+    pub poll: Account<'info, PollAccount>,
+    
+    /// Required for ranked-choice polls
+    #[account(
+        seeds = [POLL_RESULT_SEED, poll.key().as_ref()],
+        bump
+    )]
+    pub poll_result: Option<Account<'info, PollResultAccount>>,
+}
+
+pub fn finalize_poll(ctx: Context<FinalizePoll>) -> Result<()> {
+    let poll = &mut ctx.accounts.poll;
+    let clock = Clock::get()?;
+    
+    // Check poll has ended (including any reveal window)
+    require!(
+        clock.unix_timestamp >= poll.results_at(),
+        SocialError::PollNotEnded
+    );
+    require!(!poll.is_finalized(), SocialError::PollAlreadyFinalized);
+    
+    // Without quorum the poll has no winner
+    let quorum_met = poll.total_voters >= poll.quorum;
+    
+    let winners = if !quorum_met {
+        0
+    } else if poll.mode == PollMode::RankedChoice {
+        let result = ctx.accounts.poll_result
+            .as_ref()
+            .ok_or(SocialError::TallyIncomplete)?;
+        require!(result.completed_at.is_some(), SocialError::TallyIncomplete);
+        result.winner.map_or(0, |winner| 1 << winner)
+    } else if poll.election_seats > 0 {
+        poll.top_options(poll.election_seats as usize)
+    } else {
+        poll.leading_options()
+    };
+    
+    poll.winners = winners;
+    poll.quorum_met = quorum_met;
+    poll.finalized_at = Some(clock.unix_timestamp);
+    poll.open_vote_records = poll.total_voters
+        .checked_add(poll.unrevealed_votes)
+        .ok_or(SocialError::ArithmeticOverflow)?;
+    
+    emit!(PollFinalized {
+        poll: poll.key(),
+        winners,
+        total_voters: poll.total_voters,
+        quorum_met,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
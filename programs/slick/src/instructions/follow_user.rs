@@ -7,6 +7,8 @@ use crate::{constants::*, errors::*, events::*, state::*};
 /// - Cannot follow yourself
 /// - Cannot follow the same user twice
 /// - Both profiles must exist
+/// - Followed user must not have blocked the follower
+/// - Followed profile must not require approval (use request_follow)
 /// 
 /// Events: UserFollowed
 #[derive(Accounts)]
@@ -21,6 +23,14 @@ pub struct FollowUser<'info> {
     #[account(mut)]
     pub followed_profile: Account<'info, ProfileAccount>,
     
+    /// CHECK: Block PDA from followed to follower, must not exist
+    #[account(
+        seeds = [BLOCK_SEED, followed_profile.key().as_ref(), follower_profile.key().as_ref()],
+        bump,
+        constraint = block.data_is_empty() @ SocialError::BlockedByUser
+    )]
+    pub block: UncheckedAccount<'info>,
+    
     #[account(
         init,
         payer = owner,
@@ -43,6 +53,12 @@ pub fn follow_user(ctx: Context<FollowUser>) -> Result<()> {
         SocialError::CannotFollowSelf
     );
     
+    // Private profiles go through request_follow
+    require!(
+        !ctx.accounts.followed_profile.requires_approval,
+        SocialError::FollowRequiresApproval
+    );
+    
     let follow = &mut ctx.accounts.follow;
     let clock = Clock::get()?;
     
@@ -0,0 +1,168 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// Escrow a prize for the winner of a profile poll
+/// 
+/// `fund_poll_prize` escrows SOL in the prize account; `fund_poll_prize_token`
+/// escrows SPL tokens in a vault owned by the prize account. Send either in
+/// the same transaction as `create_poll` so the prize is known before anyone
+/// votes. `settle_prize_poll` pays it out once the poll is finalized.
+/// 
+/// Validation:
+/// - Signer must be the poll creator
+/// - Poll options must be profiles
+/// - Poll must not have ended or received any votes
+/// - Amount must be greater than zero
+/// - One prize per poll
+/// 
+/// Events: PrizeFunded
+#[derive(Accounts)]
+pub struct FundPollPrize<'info> {
+    #[account(
+        mut,
+        constraint = poll.created_by == creator.key() @ SocialError::NotPollCreator
+    )]
+    pub poll: Account<'info, PollAccount>,
+    
+    #[account(
+        init,
+        payer = creator,
+        space = PRIZE_SIZE,
+        seeds = [PRIZE_SEED, poll.key().as_ref()],
+        bump
+    )]
+    pub prize: Account<'info, PrizeAccount>,
+    
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FundPollPrizeToken<'info> {
+    #[account(
+        mut,
+        constraint = poll.created_by == creator.key() @ SocialError::NotPollCreator
+    )]
+    pub poll: Account<'info, PollAccount>,
+    
+    #[account(
+        init,
+        payer = creator,
+        space = PRIZE_SIZE,
+        seeds = [PRIZE_SEED, poll.key().as_ref()],
+        bump
+    )]
+    pub prize: Account<'info, PrizeAccount>,
+    
+    pub prize_mint: Account<'info, Mint>,
+    
+    #[account(
+        mut,
+        token::mint = prize_mint,
+        token::authority = creator
+    )]
+    pub creator_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        init,
+        payer = creator,
+        seeds = [PRIZE_VAULT_SEED, poll.key().as_ref()],
+        bump,
+        token::mint = prize_mint,
+        token::authority = prize
+    )]
+    pub prize_vault: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn fund_poll_prize(ctx: Context<FundPollPrize>, amount: u64) -> Result<()> {
+    // Escrow the lamports on top of the prize account's rent
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.creator.to_account_info(),
+                to: ctx.accounts.prize.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    record_prize(
+        &mut ctx.accounts.poll,
+        &mut ctx.accounts.prize,
+        ctx.accounts.creator.key(),
+        None,
+        amount,
+    )
+}
+
+pub fn fund_poll_prize_token(ctx: Context<FundPollPrizeToken>, amount: u64) -> Result<()> {
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.creator_token_account.to_account_info(),
+                to: ctx.accounts.prize_vault.to_account_info(),
+                authority: ctx.accounts.creator.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    record_prize(
+        &mut ctx.accounts.poll,
+        &mut ctx.accounts.prize,
+        ctx.accounts.creator.key(),
+        Some(ctx.accounts.prize_mint.key()),
+        amount,
+    )
+}
+
+/// Validates the poll can take a prize and records it
+fn record_prize(
+    poll: &mut Account<PollAccount>,
+    prize: &mut Account<PrizeAccount>,
+    funder: Pubkey,
+    mint: Option<Pubkey>,
+    amount: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    
+    require!(amount > 0, SocialError::InvalidPrizeAmount);
+    require!(
+        poll.option_kind == PollOptionKind::Profile
+            && clock.unix_timestamp < poll.end_time
+            && poll.total_voters == 0
+            && poll.unrevealed_votes == 0,
+        SocialError::InvalidPrizePoll
+    );
+    
+    prize.poll = poll.key();
+    prize.funder = funder;
+    prize.mint = mint;
+    prize.amount = amount;
+    prize.funded_at = clock.unix_timestamp;
+    
+    poll.has_prize = true;
+    
+    emit!(PrizeFunded {
+        poll: poll.key(),
+        prize: prize.key(),
+        mint,
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
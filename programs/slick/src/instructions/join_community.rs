@@ -1,25 +1,46 @@
 use anchor_lang::prelude::*;
-use crate::{constants::*, errors::*, events::*, state::*};
+use anchor_lang::system_program;
+use crate::{constants::*, errors::*, events::*, state::*, utils::*};
 
 /// Join an existing community
 /// 
 /// Validation:
 /// - User must have a profile
 /// - User cannot join the same community twice
+/// - User must not be banned from the community
+/// - Community must be open to new members, or to the creator's active
+///   subscribers
 /// - Community must exist
 /// 
+/// The community's join fee, if any, is paid to the creator.
+/// 
 /// Events: CommunityJoined
 #[derive(Accounts)]
 pub struct JoinCommunity<'info> {
-    #[account(mut)]
+    #[account(
+        mut,
+        has_one = creator
+    )]
     pub community: Account<'info, CommunityAccount>,
     
+    /// CHECK: Community creator, receives the join fee
+    #[account(mut)]
+    pub creator: UncheckedAccount<'info>,
+    
     #[account(
         seeds = [PROFILE_SEED, user.key().as_ref()],
         bump
     )]
     pub user_profile: Account<'info, ProfileAccount>,
     
+    /// CHECK: Ban record PDA, must not exist for the user to join
+    #[account(
+        seeds = [BAN_SEED, community.key().as_ref(), user.key().as_ref()],
+        bump,
+        constraint = ban.data_is_empty() @ SocialError::UserBanned
+    )]
+    pub ban: UncheckedAccount<'info>,
+    
     #[account(
         init,
         payer = user,
@@ -29,6 +50,9 @@ pub struct JoinCommunity<'info> {
     )]
     pub membership: Account<'info, MembershipAccount>,
     
+    /// User's subscription to the creator, required by subscriber-only communities
+    pub subscription: Option<Account<'info, SubscriptionAccount>>,
+    
     #[account(mut)]
     pub user: Signer<'info>,
     
@@ -40,6 +64,31 @@ pub fn join_community(ctx: Context<JoinCommunity>) -> Result<()> {
     let membership = &mut ctx.accounts.membership;
     let clock = Clock::get()?;
     
+    match community.join_policy {
+        JoinPolicy::Open => {}
+        JoinPolicy::Closed => return err!(SocialError::CommunityClosed),
+        JoinPolicy::Subscribers => require_active_subscription(
+            ctx.accounts.subscription.as_deref(),
+            &community.creator,
+            &ctx.accounts.user.key(),
+            clock.unix_timestamp,
+        )?,
+    }
+    
+    // Pay the join fee to the creator
+    if community.join_fee > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.user.to_account_info(),
+                    to: ctx.accounts.creator.to_account_info(),
+                },
+            ),
+            community.join_fee,
+        )?;
+    }
+    
     membership.community = community.key();
     membership.user = ctx.accounts.user.key();
     membership.joined_at = clock.unix_timestamp;
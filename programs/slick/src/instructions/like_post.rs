@@ -1,12 +1,15 @@
 use anchor_lang::prelude::*;
-use crate::{constants::*, errors::*, events::*, state::*};
+use crate::{constants::*, errors::*, events::*, state::*, utils::*};
 
 /// Like a post
 /// 
 /// Validation:
 /// - User cannot like their own post
 /// - User cannot like the same post twice
-/// - User must be a member of the community
+/// - User must be a member of the community (community posts only)
+/// - Post must not be locked
+/// - Post author must not have blocked the user
+/// - Subscriber-only posts need an active subscription to the author
 /// 
 /// Events: PostLiked
 #[derive(Accounts)]
@@ -18,7 +21,7 @@ pub struct LikePost<'info> {
         seeds = [MEMBERSHIP_SEED, post.community.as_ref(), liker.key().as_ref()],
         bump
     )]
-    pub membership: Account<'info, MembershipAccount>,
+    pub membership: Option<Account<'info, MembershipAccount>>,
     
     #[account(
         init,
@@ -29,6 +32,12 @@ pub struct LikePost<'info> {
     )]
     pub like: Account<'info, LikeAccount>,
     
+    /// CHECK: Block PDA from the post author to the liker, verified in handler
+    pub block: Option<UncheckedAccount<'info>>,
+    
+    /// Liker's subscription to the post author, required on subscriber-only posts
+    pub subscription: Option<Account<'info, SubscriptionAccount>>,
+    
     #[account(mut)]
     pub liker: Signer<'info>,
     
@@ -40,12 +49,44 @@ pub fn like_post(ctx: Context<LikePost>) -> Result<()> {
     let like = &mut ctx.accounts.like;
     let clock = Clock::get()?;
     
+    // Community posts can only be liked by members
+    if post.scope == PostScope::Community {
+        require!(
+            ctx.accounts.membership.is_some(),
+            SocialError::NotCommunityMember
+        );
+    }
+    
+    // Check post is not locked
+    require!(
+        !post.is_locked(clock.unix_timestamp),
+        SocialError::PostLocked
+    );
+    
     // Check not liking own post (if post is not anonymous)
     if let Some(author) = post.author {
         require!(
             author != ctx.accounts.liker.key(),
             SocialError::CannotLikeOwnPost
         );
+        
+        // Check the author has not blocked the liker
+        require_not_blocked(
+            ctx.accounts.block.as_deref(),
+            &author,
+            &ctx.accounts.liker.key(),
+            ctx.program_id,
+        )?;
+        
+        // Subscriber-only posts can only be liked by subscribers
+        if post.subscribers_only {
+            require_active_subscription(
+                ctx.accounts.subscription.as_deref(),
+                &author,
+                &ctx.accounts.liker.key(),
+                clock.unix_timestamp,
+            )?;
+        }
     }
     
     like.post = post.key();
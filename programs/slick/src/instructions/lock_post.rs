@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// Lock a post to stop comments, likes and tips
+/// 
+/// The lock can be open-ended or expire automatically at `locked_until`.
+/// Community moderators pass the community (and their moderator record);
+/// authors can lock their own posts without it.
+/// 
+/// Validation:
+/// - Signer must be the post author or a community moderator
+/// - Lock expiry (if any) must be in the future
+/// 
+/// Events: PostLocked
+#[derive(Accounts)]
+pub struct LockPost<'info> {
+    #[account(mut)]
+    pub post: Account<'info, PostAccount>,
+    
+    #[account(
+        constraint = community.key() == post.community @ SocialError::PostNotInCommunity
+    )]
+    pub community: Option<Account<'info, CommunityAccount>>,
+    
+    #[account(
+        seeds = [MODERATOR_SEED, post.community.as_ref(), authority.key().as_ref()],
+        bump
+    )]
+    pub moderator: Option<Account<'info, ModeratorAccount>>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn lock_post(ctx: Context<LockPost>, locked_until: Option<i64>) -> Result<()> {
+    let post = &mut ctx.accounts.post;
+    let authority = ctx.accounts.authority.key();
+    let clock = Clock::get()?;
+    
+    // Check signer is the author or a moderator
+    let is_author = post.author == Some(authority);
+    let is_moderator = ctx.accounts.community
+        .as_ref()
+        .is_some_and(|community| {
+            community.is_moderator(&authority, ctx.accounts.moderator.as_deref(), clock.unix_timestamp)
+        });
+    require!(
+        is_author || is_moderator,
+        SocialError::NotAuthorOrModerator
+    );
+    
+    if let Some(until) = locked_until {
+        require!(
+            until > clock.unix_timestamp,
+            SocialError::InvalidLockExpiry
+        );
+    }
+    
+    post.locked = true;
+    post.locked_until = locked_until;
+    
+    emit!(PostLocked {
+        post: post.key(),
+        locked_by: authority,
+        locked_until,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
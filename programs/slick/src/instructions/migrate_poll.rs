@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_lang::Discriminator;
+use crate::{constants::*, errors::*, state::*};
+
+/// Upgrade a poll or vote record created before polls gained modes
+///
+/// Legacy accounts were allocated at their original size, so the fields
+/// added since no longer fit. `migrate_poll` and `migrate_vote` grow the
+/// account to the current size (the payer covers the extra rent) and fill in
+/// what the legacy layout implied:
+/// - Polls are single-choice profile polls, with one voter per counted vote
+/// - Votes select only `option_index` with a weight of 1
+///
+/// Permissionless, and each account only needs it once.
+///
+/// Validation:
+/// - Account must be a PollAccount (or VoteAccount) owned by this program
+/// - Account must be smaller than the current size
+#[derive(Accounts)]
+pub struct MigratePoll<'info> {
+    /// CHECK: Legacy poll, verified in handler
+    #[account(mut)]
+    pub poll: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateVote<'info> {
+    /// CHECK: Legacy vote record, verified in handler
+    #[account(mut)]
+    pub vote: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_poll(ctx: Context<MigratePoll>) -> Result<()> {
+    let poll_info = ctx.accounts.poll.to_account_info();
+
+    grow_legacy_account(
+        &poll_info,
+        PollAccount::DISCRIMINATOR,
+        POLL_SIZE,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        ctx.program_id,
+    )?;
+
+    // The new fields decode from the zeroed tail; fix up the ones whose
+    // zero value does not match the legacy behaviour
+    let mut poll = PollAccount::try_deserialize(&mut &poll_info.data.borrow()[..])?;
+    poll.max_selections = 1;
+    poll.start_time = poll.created_at;
+    poll.total_voters = poll.votes_per_option
+        .iter()
+        .try_fold(0u32, |total, &votes| total.checked_add(votes))
+        .ok_or(SocialError::ArithmeticOverflow)?;
+    poll.try_serialize(&mut &mut poll_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
+
+pub fn migrate_vote(ctx: Context<MigrateVote>) -> Result<()> {
+    let vote_info = ctx.accounts.vote.to_account_info();
+
+    grow_legacy_account(
+        &vote_info,
+        VoteAccount::DISCRIMINATOR,
+        VOTE_SIZE,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        ctx.program_id,
+    )?;
+
+    let mut vote = VoteAccount::try_deserialize(&mut &vote_info.data.borrow()[..])?;
+    vote.selections = 1 << vote.option_index;
+    vote.weight = 1;
+    vote.try_serialize(&mut &mut vote_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
+
+/// Checks a legacy account's owner and discriminator, tops up its rent and
+/// grows it to `size` with a zeroed tail
+fn grow_legacy_account<'info>(
+    account: &AccountInfo<'info>,
+    discriminator: &[u8],
+    size: usize,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    program_id: &Pubkey,
+) -> Result<()> {
+    require_keys_eq!(*account.owner, *program_id, ErrorCode::AccountOwnedByWrongProgram);
+    require!(
+        account.data.borrow().starts_with(discriminator),
+        ErrorCode::AccountDiscriminatorMismatch
+    );
+    require!(account.data_len() < size, SocialError::AccountAlreadyMigrated);
+
+    let rent_due = Rent::get()?
+        .minimum_balance(size)
+        .saturating_sub(account.lamports());
+    if rent_due > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: payer.to_account_info(),
+                    to: account.clone(),
+                },
+            ),
+            rent_due,
+        )?;
+    }
+
+    account.resize(size)?;
+
+    Ok(())
+}
@@ -0,0 +1,176 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::metadata::{
+    self,
+    mpl_token_metadata::types::{Creator, DataV2},
+    CreateMasterEditionV3, CreateMetadataAccountsV3, Metadata,
+};
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// Mint a post as a one-of-one NFT
+/// 
+/// Creates the post's mint (a PDA, so each post can only be minted once),
+/// mints the single token to the author, and creates Metaplex metadata
+/// pointing at the post's `content_uri` plus a master edition with no
+/// prints. The author is the verified creator and update authority.
+/// 
+/// Validation:
+/// - Signer must be the post author (not anonymous)
+/// - Post must not already be minted
+/// - Name and symbol must fit Metaplex limits
+/// - Seller fee must be at most 10000 basis points
+/// 
+/// Events: PostNftMinted
+#[derive(Accounts)]
+pub struct MintPostNft<'info> {
+    #[account(
+        mut,
+        constraint = post.author == Some(author.key()) @ SocialError::NotPostAuthor
+    )]
+    pub post: Account<'info, PostAccount>,
+    
+    #[account(
+        init,
+        payer = author,
+        seeds = [POST_MINT_SEED, post.key().as_ref()],
+        bump,
+        mint::decimals = 0,
+        mint::authority = author,
+        mint::freeze_authority = author
+    )]
+    pub mint: Box<Account<'info, Mint>>,
+    
+    #[account(
+        init,
+        payer = author,
+        associated_token::mint = mint,
+        associated_token::authority = author
+    )]
+    pub author_token_account: Box<Account<'info, TokenAccount>>,
+    
+    /// CHECK: Metadata PDA, created by the token metadata program
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), mint.key().as_ref()],
+        bump,
+        seeds::program = token_metadata_program.key()
+    )]
+    pub metadata: UncheckedAccount<'info>,
+    
+    /// CHECK: Master edition PDA, created by the token metadata program
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), mint.key().as_ref(), b"edition"],
+        bump,
+        seeds::program = token_metadata_program.key()
+    )]
+    pub master_edition: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub author: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    
+    pub token_metadata_program: Program<'info, Metadata>,
+    
+    pub system_program: Program<'info, System>,
+    
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn mint_post_nft(
+    ctx: Context<MintPostNft>,
+    name: String,
+    symbol: String,
+    seller_fee_basis_points: u16,
+) -> Result<()> {
+    require!(name.len() <= MAX_NFT_NAME_LEN, SocialError::NftNameTooLong);
+    require!(symbol.len() <= MAX_NFT_SYMBOL_LEN, SocialError::NftSymbolTooLong);
+    require!(
+        seller_fee_basis_points <= MAX_THRESHOLD_BPS,
+        SocialError::InvalidSellerFee
+    );
+    require!(ctx.accounts.post.nft_mint.is_none(), SocialError::PostAlreadyMinted);
+    
+    let author = ctx.accounts.author.to_account_info();
+    let clock = Clock::get()?;
+    
+    // Mint the single token to the author
+    token::mint_to(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.author_token_account.to_account_info(),
+                authority: author.clone(),
+            },
+        ),
+        1,
+    )?;
+    
+    // Metadata points at the post's content
+    metadata::create_metadata_accounts_v3(
+        CpiContext::new(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            CreateMetadataAccountsV3 {
+                metadata: ctx.accounts.metadata.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                mint_authority: author.clone(),
+                payer: author.clone(),
+                update_authority: author.clone(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+            },
+        ),
+        DataV2 {
+            name,
+            symbol,
+            uri: ctx.accounts.post.content_uri.clone(),
+            seller_fee_basis_points,
+            creators: Some(vec![Creator {
+                address: author.key(),
+                verified: true,
+                share: 100,
+            }]),
+            collection: None,
+            uses: None,
+        },
+        true,
+        true,
+        None,
+    )?;
+    
+    // The master edition takes over the mint, fixing the supply at one
+    metadata::create_master_edition_v3(
+        CpiContext::new(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            CreateMasterEditionV3 {
+                edition: ctx.accounts.master_edition.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                update_authority: author.clone(),
+                mint_authority: author.clone(),
+                payer: author.clone(),
+                metadata: ctx.accounts.metadata.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+            },
+        ),
+        Some(0),
+    )?;
+    
+    let post = &mut ctx.accounts.post;
+    post.nft_mint = Some(ctx.accounts.mint.key());
+    
+    emit!(PostNftMinted {
+        post: post.key(),
+        mint: ctx.accounts.mint.key(),
+        author: author.key(),
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
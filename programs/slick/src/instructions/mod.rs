@@ -1,30 +1,92 @@
 pub mod create_post;
+pub mod create_profile_post;
 pub mod like_post;
 pub mod unlike_post;
 pub mod comment_on_post;
 pub mod tip_post;
+pub mod tip_comment;
+pub mod tip_profile;
+pub mod repost;
+pub mod pin_post;
+pub mod unpin_post;
+pub mod lock_post;
+pub mod unlock_post;
+pub mod create_bounty;
+pub mod award_bounty;
+pub mod refund_bounty;
+pub mod set_post_price;
+pub mod purchase_post;
+pub mod set_post_access;
+pub mod mint_post_nft;
 
 pub use create_post::*;
+pub use create_profile_post::*;
 pub use like_post::*;
 pub use unlike_post::*;
 pub use comment_on_post::*;
 pub use tip_post::*;
+pub use tip_comment::*;
+pub use tip_profile::*;
+pub use repost::*;
+pub use pin_post::*;
+pub use unpin_post::*;
+pub use lock_post::*;
+pub use unlock_post::*;
+pub use create_bounty::*;
+pub use award_bounty::*;
+pub use refund_bounty::*;
+pub use set_post_price::*;
+pub use purchase_post::*;
+pub use set_post_access::*;
+pub use mint_post_nft::*;
 
 
 pub mod create_poll;
 pub mod vote_poll;
+pub mod vote_poll_weighted;
+pub mod withdraw_vote_tokens;
+pub mod commit_vote;
+pub mod reveal_vote;
+pub mod change_vote;
+pub mod revoke_vote;
+pub mod tally_poll;
+pub mod finalize_poll;
+pub mod close_vote_record;
+pub mod close_poll;
+pub mod migrate_poll;
+pub mod seat_elected_moderator;
+pub mod fund_poll_prize;
+pub mod settle_prize_poll;
 
 pub use create_poll::*;
 pub use vote_poll::*;
+pub use vote_poll_weighted::*;
+pub use withdraw_vote_tokens::*;
+pub use commit_vote::*;
+pub use reveal_vote::*;
+pub use change_vote::*;
+pub use revoke_vote::*;
+pub use tally_poll::*;
+pub use finalize_poll::*;
+pub use close_vote_record::*;
+pub use close_poll::*;
+pub use migrate_poll::*;
+pub use seat_elected_moderator::*;
+pub use fund_poll_prize::*;
+pub use settle_prize_poll::*;
 
 
 pub mod create_community;
 pub mod join_community;
 pub mod leave_community;
+pub mod add_moderator;
+pub mod remove_moderator;
 
 pub use create_community::*;
 pub use join_community::*;
 pub use leave_community::*;
+pub use add_moderator::*;
+pub use remove_moderator::*;
 
 pub mod create_profile;
 pub use create_profile::*;
@@ -33,6 +95,51 @@ pub mod follow_user;
 pub use follow_user::*;
 pub mod unfollow_user;
 pub use unfollow_user::*;
+pub mod request_follow;
+pub use request_follow::*;
+pub mod approve_follow;
+pub use approve_follow::*;
+pub mod reject_follow;
+pub use reject_follow::*;
+pub mod block_user;
+pub use block_user::*;
+pub mod unblock_user;
+pub use unblock_user::*;
 
 pub mod update_profile;
-pub use update_profile::*;
\ No newline at end of file
+pub use update_profile::*;
+pub mod set_profile_privacy;
+pub use set_profile_privacy::*;
+
+pub mod pin_profile_post;
+pub use pin_profile_post::*;
+pub mod unpin_profile_post;
+pub use unpin_profile_post::*;
+
+pub mod report_post;
+pub mod report_comment;
+pub mod resolve_report;
+pub mod set_report_threshold;
+
+pub use report_post::*;
+pub use report_comment::*;
+pub use resolve_report::*;
+pub use set_report_threshold::*;
+
+pub mod set_governance_config;
+pub mod create_proposal;
+pub mod execute_proposal;
+pub mod close_proposal;
+
+pub use set_governance_config::*;
+pub use create_proposal::*;
+pub use execute_proposal::*;
+pub use close_proposal::*;
+
+pub mod set_subscription_tiers;
+pub mod subscribe;
+pub mod cancel_subscription;
+
+pub use set_subscription_tiers::*;
+pub use subscribe::*;
+pub use cancel_subscription::*;
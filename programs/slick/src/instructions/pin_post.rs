@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// Pin a post to the top of a community
+/// 
+/// Validation:
+/// - Signer must be the community creator or a moderator
+/// - Post must belong to the community
+/// - Post cannot be pinned twice
+/// - At most MAX_PINNED_POSTS posts can be pinned
+/// 
+/// Events: PostPinned
+#[derive(Accounts)]
+pub struct PinPost<'info> {
+    #[account(mut)]
+    pub community: Account<'info, CommunityAccount>,
+    
+    #[account(
+        seeds = [MODERATOR_SEED, community.key().as_ref(), authority.key().as_ref()],
+        bump
+    )]
+    pub moderator: Option<Account<'info, ModeratorAccount>>,
+    
+    #[account(
+        constraint = post.scope == PostScope::Community
+            && post.community == community.key() @ SocialError::PostNotInCommunity
+    )]
+    pub post: Account<'info, PostAccount>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn pin_post(ctx: Context<PinPost>) -> Result<()> {
+    let community = &mut ctx.accounts.community;
+    let post_key = ctx.accounts.post.key();
+    let authority = ctx.accounts.authority.key();
+    let clock = Clock::get()?;
+    
+    // Check signer can moderate
+    require!(
+        community.is_moderator(&authority, ctx.accounts.moderator.as_deref(), clock.unix_timestamp),
+        SocialError::NotModerator
+    );
+    
+    require!(
+        !community.pinned_posts.contains(&post_key),
+        SocialError::PostAlreadyPinned
+    );
+    require!(
+        community.pinned_posts.len() < MAX_PINNED_POSTS,
+        SocialError::PinnedPostsFull
+    );
+    
+    community.pinned_posts.push(post_key);
+    
+    emit!(PostPinned {
+        post: post_key,
+        target: community.key(),
+        pinned_by: authority,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
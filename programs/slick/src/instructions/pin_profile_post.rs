@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// Pin one of your own posts to the top of your profile
+/// 
+/// Any post authored by the profile owner can be pinned, whether it was
+/// posted in a community or on the profile itself.
+/// 
+/// Validation:
+/// - Only the profile owner can pin
+/// - Post must be authored by the profile owner
+/// - Post cannot be pinned twice
+/// - At most MAX_PROFILE_PINNED_POSTS posts can be pinned
+/// 
+/// Events: PostPinned
+#[derive(Accounts)]
+pub struct PinProfilePost<'info> {
+    #[account(
+        mut,
+        seeds = [PROFILE_SEED, owner.key().as_ref()],
+        bump,
+        has_one = owner
+    )]
+    pub profile: Account<'info, ProfileAccount>,
+    
+    #[account(
+        constraint = post.author == Some(owner.key()) @ SocialError::NotPostAuthor
+    )]
+    pub post: Account<'info, PostAccount>,
+    
+    pub owner: Signer<'info>,
+}
+
+pub fn pin_profile_post(ctx: Context<PinProfilePost>) -> Result<()> {
+    let profile = &mut ctx.accounts.profile;
+    let post_key = ctx.accounts.post.key();
+    let clock = Clock::get()?;
+    
+    require!(
+        !profile.pinned_posts.contains(&post_key),
+        SocialError::PostAlreadyPinned
+    );
+    require!(
+        profile.pinned_posts.len() < MAX_PROFILE_PINNED_POSTS,
+        SocialError::PinnedPostsFull
+    );
+    
+    profile.pinned_posts.push(post_key);
+    
+    emit!(PostPinned {
+        post: post_key,
+        target: profile.key(),
+        pinned_by: profile.owner,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::{constants::*, errors::*, events::*, state::*, utils::*};
+
+/// Buy access to a paid post
+/// 
+/// Pays the post's price to its author and records an unlock receipt for
+/// the buyer. The buyer passes the price they expect to pay so a price
+/// change cannot overcharge them.
+/// 
+/// Validation:
+/// - Post must have a price, equal to `price_lamports`
+/// - Buyer cannot buy their own post
+/// - Post author must not have blocked the buyer
+/// - One receipt per buyer per post
+/// 
+/// Events: PostPurchased
+#[derive(Accounts)]
+pub struct PurchasePost<'info> {
+    #[account(mut)]
+    pub post: Account<'info, PostAccount>,
+    
+    #[account(
+        init,
+        payer = buyer,
+        space = UNLOCK_SIZE,
+        seeds = [UNLOCK_SEED, post.key().as_ref(), buyer.key().as_ref()],
+        bump
+    )]
+    pub unlock: Account<'info, UnlockAccount>,
+    
+    /// CHECK: This is the post author who receives the payment
+    #[account(
+        mut,
+        constraint = post.author == Some(author.key()) @ SocialError::PostNotForSale
+    )]
+    pub author: UncheckedAccount<'info>,
+    
+    /// CHECK: Block PDA from the post author to the buyer, verified in handler
+    pub block: Option<UncheckedAccount<'info>>,
+    
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn purchase_post(ctx: Context<PurchasePost>, price_lamports: u64) -> Result<()> {
+    let post = &mut ctx.accounts.post;
+    let author = ctx.accounts.author.key();
+    let buyer = ctx.accounts.buyer.key();
+    let clock = Clock::get()?;
+    
+    require!(post.price_lamports > 0, SocialError::PostNotForSale);
+    require!(post.price_lamports == price_lamports, SocialError::PostPriceChanged);
+    require!(author != buyer, SocialError::CannotPurchaseOwnPost);
+    
+    // Check the author has not blocked the buyer
+    require_not_blocked(
+        ctx.accounts.block.as_deref(),
+        &author,
+        &buyer,
+        ctx.program_id,
+    )?;
+    
+    // Pay the author
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.buyer.to_account_info(),
+                to: ctx.accounts.author.to_account_info(),
+            },
+        ),
+        price_lamports,
+    )?;
+    
+    let unlock = &mut ctx.accounts.unlock;
+    unlock.post = post.key();
+    unlock.buyer = buyer;
+    unlock.price_paid = price_lamports;
+    unlock.unlocked_at = clock.unix_timestamp;
+    
+    post.unlocks_count = post.unlocks_count
+        .checked_add(1)
+        .ok_or(SocialError::ArithmeticOverflow)?;
+    
+    emit!(PostPurchased {
+        post: post.key(),
+        unlock: unlock.key(),
+        buyer,
+        author,
+        price_lamports,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
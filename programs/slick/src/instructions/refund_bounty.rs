@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// Refund an expired bounty to the post author
+/// 
+/// Permissionless once the bounty expires. Closes the bounty account,
+/// returning the escrowed lamports and rent to the author.
+/// 
+/// Validation:
+/// - Bounty must have expired
+/// 
+/// Events: BountyRefunded
+#[derive(Accounts)]
+pub struct RefundBounty<'info> {
+    #[account(mut)]
+    pub post: Account<'info, PostAccount>,
+    
+    #[account(
+        mut,
+        close = author,
+        has_one = post,
+        has_one = author,
+        seeds = [BOUNTY_SEED, post.key().as_ref()],
+        bump
+    )]
+    pub bounty: Account<'info, BountyAccount>,
+    
+    /// CHECK: Post author who funded the bounty, verified by has_one
+    #[account(mut)]
+    pub author: UncheckedAccount<'info>,
+}
+
+pub fn refund_bounty(ctx: Context<RefundBounty>) -> Result<()> {
+    let bounty = &ctx.accounts.bounty;
+    let clock = Clock::get()?;
+    
+    require!(clock.unix_timestamp >= bounty.expires_at, SocialError::BountyNotExpired);
+    
+    emit!(BountyRefunded {
+        bounty: bounty.key(),
+        post: bounty.post,
+        author: bounty.author,
+        amount: bounty.amount,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    ctx.accounts.post.bounty_lamports = 0;
+    
+    Ok(())
+}
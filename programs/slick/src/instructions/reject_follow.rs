@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, events::*, state::*};
+
+/// Reject a pending follow request
+/// 
+/// The request is closed and its rent refunded to the requester.
+/// 
+/// Validation:
+/// - Only the followed profile's owner can reject
+/// - Request must exist
+/// 
+/// Events: FollowRejected
+#[derive(Accounts)]
+pub struct RejectFollow<'info> {
+    #[account(
+        seeds = [PROFILE_SEED, owner.key().as_ref()],
+        bump,
+        has_one = owner
+    )]
+    pub followed_profile: Account<'info, ProfileAccount>,
+    
+    pub follower_profile: Account<'info, ProfileAccount>,
+    
+    #[account(
+        mut,
+        close = requester,
+        seeds = [FOLLOW_REQUEST_SEED, follower_profile.key().as_ref(), followed_profile.key().as_ref()],
+        bump
+    )]
+    pub follow_request: Account<'info, FollowRequestAccount>,
+    
+    /// CHECK: Wallet of the requester, receives the request's rent
+    #[account(
+        mut,
+        address = follower_profile.owner
+    )]
+    pub requester: UncheckedAccount<'info>,
+    
+    pub owner: Signer<'info>,
+}
+
+pub fn reject_follow(ctx: Context<RejectFollow>) -> Result<()> {
+    let clock = Clock::get()?;
+    
+    emit!(FollowRejected {
+        follower: ctx.accounts.follow_request.follower,
+        followed: ctx.accounts.follow_request.followed,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// Revoke moderator rights
+/// 
+/// Validation:
+/// - Only the community creator can remove moderators
+/// - Moderator record must belong to the community
+/// 
+/// Events: ModeratorRemoved
+#[derive(Accounts)]
+pub struct RemoveModerator<'info> {
+    #[account(
+        has_one = creator @ SocialError::NotCommunityCreator
+    )]
+    pub community: Account<'info, CommunityAccount>,
+    
+    #[account(
+        mut,
+        close = creator,
+        seeds = [MODERATOR_SEED, community.key().as_ref(), moderator.user.as_ref()],
+        bump,
+        has_one = community
+    )]
+    pub moderator: Account<'info, ModeratorAccount>,
+    
+    #[account(mut)]
+    pub creator: Signer<'info>,
+}
+
+pub fn remove_moderator(ctx: Context<RemoveModerator>) -> Result<()> {
+    let clock = Clock::get()?;
+    
+    emit!(ModeratorRemoved {
+        community: ctx.accounts.community.key(),
+        moderator: ctx.accounts.moderator.user,
+        removed_by: ctx.accounts.creator.key(),
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
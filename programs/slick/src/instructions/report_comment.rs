@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// Report a comment on a community post for abuse
+/// 
+/// Creates an open report in the community's moderation queue and bumps the
+/// comment's report counter. Once the counter reaches the community's report
+/// threshold the comment is hidden until a moderator reviews it.
+/// 
+/// Validation:
+/// - Comment must belong to a post in the community
+/// - Reporter must be a member of the community
+/// - User cannot report the same comment twice
+/// - Evidence URI must be within length limits
+/// 
+/// Events: ContentReported, ContentHidden (when threshold is crossed)
+#[derive(Accounts)]
+pub struct ReportComment<'info> {
+    pub community: Account<'info, CommunityAccount>,
+    
+    #[account(
+        constraint = post.scope == PostScope::Community
+            && post.community == community.key() @ SocialError::PostNotInCommunity
+    )]
+    pub post: Account<'info, PostAccount>,
+    
+    #[account(
+        mut,
+        has_one = post
+    )]
+    pub comment: Account<'info, CommentAccount>,
+    
+    #[account(
+        seeds = [MEMBERSHIP_SEED, community.key().as_ref(), reporter.key().as_ref()],
+        bump
+    )]
+    pub membership: Account<'info, MembershipAccount>,
+    
+    #[account(
+        init,
+        payer = reporter,
+        space = REPORT_SIZE,
+        seeds = [REPORT_SEED, comment.key().as_ref(), reporter.key().as_ref()],
+        bump
+    )]
+    pub report: Account<'info, ReportAccount>,
+    
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn report_comment(
+    ctx: Context<ReportComment>,
+    reason: ReportReason,
+    evidence_uri: String,
+) -> Result<()> {
+    // Validate evidence URI length
+    require!(
+        evidence_uri.len() <= MAX_EVIDENCE_URI_LEN,
+        SocialError::EvidenceUriTooLong
+    );
+    
+    let community = &ctx.accounts.community;
+    let comment = &mut ctx.accounts.comment;
+    let report = &mut ctx.accounts.report;
+    let clock = Clock::get()?;
+    
+    report.community = community.key();
+    report.target = comment.key();
+    report.target_kind = ReportTarget::Comment;
+    report.target_author = Some(comment.commenter);
+    report.reporter = ctx.accounts.reporter.key();
+    report.reason = reason;
+    report.evidence_uri = evidence_uri;
+    report.status = ReportStatus::Open;
+    report.resolved_by = None;
+    report.created_at = clock.unix_timestamp;
+    report.resolved_at = None;
+    report.hid_target = false;
+    
+    // Increment report count
+    comment.reports_count = comment.reports_count
+        .checked_add(1)
+        .ok_or(SocialError::ArithmeticOverflow)?;
+    
+    emit!(ContentReported {
+        report: report.key(),
+        community: community.key(),
+        target: comment.key(),
+        target_kind: ReportTarget::Comment,
+        reporter: report.reporter,
+        reason,
+        reports_count: comment.reports_count,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    // Auto-hide once the community threshold is reached
+    if community.report_threshold > 0
+        && comment.reports_count >= community.report_threshold
+        && !comment.hidden
+    {
+        comment.hidden = true;
+        report.hid_target = true;
+        
+        emit!(ContentHidden {
+            community: community.key(),
+            target: comment.key(),
+            target_kind: ReportTarget::Comment,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+    
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// Repost (or quote) an existing post
+/// 
+/// Without a destination membership the repost is shared to the user's
+/// followers. Passing a membership shares it into that community instead.
+/// 
+/// Validation:
+/// - User cannot repost the same post twice
+/// - Destination membership (if any) must belong to the reposter
+/// - Quote URI and hash must be provided together
+/// - Quote URI must be within length limits
+/// 
+/// Events: PostReposted
+#[derive(Accounts)]
+pub struct Repost<'info> {
+    #[account(mut)]
+    pub original_post: Account<'info, PostAccount>,
+    
+    #[account(
+        constraint = destination_membership.user == reposter.key() @ SocialError::NotCommunityMember
+    )]
+    pub destination_membership: Option<Account<'info, MembershipAccount>>,
+    
+    #[account(
+        init,
+        payer = reposter,
+        space = REPOST_SIZE,
+        seeds = [REPOST_SEED, original_post.key().as_ref(), reposter.key().as_ref()],
+        bump
+    )]
+    pub repost: Account<'info, RepostAccount>,
+    
+    #[account(mut)]
+    pub reposter: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn repost(
+    ctx: Context<Repost>,
+    quote_uri: Option<String>,
+    quote_hash: Option<[u8; 32]>,
+) -> Result<()> {
+    // Validate quote fields
+    require!(
+        quote_uri.is_some() == quote_hash.is_some(),
+        SocialError::InvalidQuote
+    );
+    if let Some(ref uri) = quote_uri {
+        require!(
+            uri.len() <= MAX_CONTENT_URI_LEN,
+            SocialError::ContentUriTooLong
+        );
+    }
+    
+    let original_post = &mut ctx.accounts.original_post;
+    let repost = &mut ctx.accounts.repost;
+    let clock = Clock::get()?;
+    
+    let is_quote = quote_uri.is_some();
+    
+    repost.original_post = original_post.key();
+    repost.reposter = ctx.accounts.reposter.key();
+    repost.community = ctx.accounts.destination_membership
+        .as_ref()
+        .map(|membership| membership.community);
+    repost.quote_uri = quote_uri;
+    repost.quote_hash = quote_hash;
+    repost.reposted_at = clock.unix_timestamp;
+    
+    // Increment repost count on the original
+    original_post.reposts_count = original_post.reposts_count
+        .checked_add(1)
+        .ok_or(SocialError::ArithmeticOverflow)?;
+    
+    emit!(PostReposted {
+        repost: repost.key(),
+        original_post: original_post.key(),
+        reposter: ctx.accounts.reposter.key(),
+        community: repost.community,
+        is_quote,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// Request to follow a profile that requires approval
+/// 
+/// Validation:
+/// - Cannot request to follow yourself
+/// - Followed profile must require approval
+/// - Followed user must not have blocked the requester
+/// - Cannot have two pending requests for the same profile
+/// 
+/// Events: FollowRequested
+#[derive(Accounts)]
+pub struct RequestFollow<'info> {
+    #[account(
+        seeds = [PROFILE_SEED, owner.key().as_ref()],
+        bump,
+        has_one = owner
+    )]
+    pub follower_profile: Account<'info, ProfileAccount>,
+    
+    pub followed_profile: Account<'info, ProfileAccount>,
+    
+    /// CHECK: Block PDA from followed to follower, must not exist
+    #[account(
+        seeds = [BLOCK_SEED, followed_profile.key().as_ref(), follower_profile.key().as_ref()],
+        bump,
+        constraint = block.data_is_empty() @ SocialError::BlockedByUser
+    )]
+    pub block: UncheckedAccount<'info>,
+    
+    #[account(
+        init,
+        payer = owner,
+        space = FOLLOW_REQUEST_SIZE,
+        seeds = [FOLLOW_REQUEST_SEED, follower_profile.key().as_ref(), followed_profile.key().as_ref()],
+        bump
+    )]
+    pub follow_request: Account<'info, FollowRequestAccount>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn request_follow(ctx: Context<RequestFollow>) -> Result<()> {
+    // Check not following self
+    require!(
+        ctx.accounts.follower_profile.key() != ctx.accounts.followed_profile.key(),
+        SocialError::CannotFollowSelf
+    );
+    
+    // Public profiles are followed directly
+    require!(
+        ctx.accounts.followed_profile.requires_approval,
+        SocialError::FollowApprovalNotRequired
+    );
+    
+    let follow_request = &mut ctx.accounts.follow_request;
+    let clock = Clock::get()?;
+    
+    follow_request.follower = ctx.accounts.follower_profile.key();
+    follow_request.followed = ctx.accounts.followed_profile.key();
+    follow_request.requested_at = clock.unix_timestamp;
+    
+    emit!(FollowRequested {
+        follower: follow_request.follower,
+        followed: follow_request.followed,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
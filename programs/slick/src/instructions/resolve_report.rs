@@ -0,0 +1,196 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*, utils::*};
+
+/// Resolve an open report from the moderation queue
+/// 
+/// - Dismiss: content hidden by this report crossing the threshold is
+///   unhidden, unless a moderator has removed it
+/// - Remove: the reported content is hidden
+/// - BanAuthor: the content is hidden, a ban record is created (if the author
+///   is not already banned) and the author's membership and moderator
+///   record (if any) are closed with rent refunded to the author
+/// 
+/// Validation:
+/// - Signer must be the community creator or a moderator
+/// - Report must belong to the community and still be open
+/// - The reported post or comment must be passed
+/// - Ban, membership, moderator and author accounts are only allowed (and
+///   required) when banning the author
+/// - Authors of anonymous posts cannot be banned
+/// 
+/// Events: ReportResolved, UserBanned and ModeratorRemoved (when banning)
+#[derive(Accounts)]
+pub struct ResolveReport<'info> {
+    #[account(mut)]
+    pub community: Account<'info, CommunityAccount>,
+    
+    #[account(
+        seeds = [MODERATOR_SEED, community.key().as_ref(), authority.key().as_ref()],
+        bump
+    )]
+    pub moderator: Option<Account<'info, ModeratorAccount>>,
+    
+    #[account(
+        mut,
+        has_one = community,
+        constraint = report.status == ReportStatus::Open @ SocialError::ReportAlreadyResolved
+    )]
+    pub report: Account<'info, ReportAccount>,
+    
+    #[account(
+        mut,
+        constraint = post.key() == report.target @ SocialError::ReportTargetMismatch
+    )]
+    pub post: Option<Account<'info, PostAccount>>,
+    
+    #[account(
+        mut,
+        constraint = comment.key() == report.target @ SocialError::ReportTargetMismatch
+    )]
+    pub comment: Option<Account<'info, CommentAccount>>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = BAN_SIZE,
+        seeds = [BAN_SEED, community.key().as_ref(), report.target_author.unwrap_or_default().as_ref()],
+        bump
+    )]
+    pub ban: Option<Account<'info, BanAccount>>,
+    
+    /// CHECK: Author's membership PDA, closed if the author is still a member
+    #[account(
+        mut,
+        seeds = [MEMBERSHIP_SEED, community.key().as_ref(), report.target_author.unwrap_or_default().as_ref()],
+        bump
+    )]
+    pub author_membership: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Author's moderator PDA, closed if the author is a moderator
+    #[account(
+        mut,
+        seeds = [MODERATOR_SEED, community.key().as_ref(), report.target_author.unwrap_or_default().as_ref()],
+        bump
+    )]
+    pub author_moderator: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Author wallet that receives the closed membership and moderator rent
+    #[account(
+        mut,
+        constraint = Some(author.key()) == report.target_author @ SocialError::ReportTargetMismatch
+    )]
+    pub author: Option<UncheckedAccount<'info>>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn resolve_report(ctx: Context<ResolveReport>, outcome: ReportOutcome) -> Result<()> {
+    let accounts = ctx.accounts;
+    let authority = accounts.authority.key();
+    let clock = Clock::get()?;
+    
+    // Check signer can moderate
+    require!(
+        accounts.community.is_moderator(&authority, accounts.moderator.as_deref(), clock.unix_timestamp),
+        SocialError::NotModerator
+    );
+    
+    // Apply the decision to the reported content. Dismissing only undoes
+    // this report's own auto-hide, never a moderator's removal
+    let (hidden, removed) = match accounts.report.target_kind {
+        ReportTarget::Post => {
+            let post = accounts.post.as_deref_mut().ok_or(SocialError::ReportTargetMismatch)?;
+            (&mut post.hidden, &mut post.removed)
+        }
+        ReportTarget::Comment => {
+            let comment = accounts.comment.as_deref_mut().ok_or(SocialError::ReportTargetMismatch)?;
+            (&mut comment.hidden, &mut comment.removed)
+        }
+    };
+    match outcome {
+        ReportOutcome::Dismiss => {
+            if accounts.report.hid_target && !*removed {
+                *hidden = false;
+            }
+        }
+        ReportOutcome::Remove | ReportOutcome::BanAuthor => {
+            *hidden = true;
+            *removed = true;
+        }
+    }
+    
+    if outcome == ReportOutcome::BanAuthor {
+        let user = accounts.report.target_author.ok_or(SocialError::CannotBanAnonymousAuthor)?;
+        let ban = accounts.ban.as_mut().ok_or(SocialError::BanAccountRequired)?;
+        let membership = accounts.author_membership.as_ref().ok_or(SocialError::BanAccountRequired)?;
+        let author_moderator = accounts.author_moderator.as_ref().ok_or(SocialError::BanAccountRequired)?;
+        
+        // Revoke the author's membership if they are still a member
+        if !membership.data_is_empty() {
+            let author = accounts.author.as_ref().ok_or(SocialError::BanAccountRequired)?;
+            close_program_account(membership, author, ctx.program_id)?;
+            
+            accounts.community.member_count = accounts.community.member_count
+                .checked_sub(1)
+                .ok_or(SocialError::ArithmeticOverflow)?;
+        }
+        
+        // A banned author can no longer moderate
+        if !author_moderator.data_is_empty() {
+            let author = accounts.author.as_ref().ok_or(SocialError::BanAccountRequired)?;
+            close_program_account(author_moderator, author, ctx.program_id)?;
+            
+            emit!(ModeratorRemoved {
+                community: accounts.community.key(),
+                moderator: user,
+                removed_by: authority,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        
+        // An author who is already banned keeps the original ban record
+        if ban.user == Pubkey::default() {
+            ban.community = accounts.community.key();
+            ban.user = user;
+            ban.banned_by = authority;
+            ban.banned_at = clock.unix_timestamp;
+            
+            emit!(UserBanned {
+                community: accounts.community.key(),
+                user,
+                banned_by: authority,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+    } else {
+        require!(
+            accounts.ban.is_none()
+                && accounts.author_membership.is_none()
+                && accounts.author_moderator.is_none()
+                && accounts.author.is_none(),
+            SocialError::UnexpectedBanAccount
+        );
+    }
+    
+    let report = &mut accounts.report;
+    report.status = match outcome {
+        ReportOutcome::Dismiss => ReportStatus::Dismissed,
+        ReportOutcome::Remove => ReportStatus::Removed,
+        ReportOutcome::BanAuthor => ReportStatus::AuthorBanned,
+    };
+    report.resolved_by = Some(authority);
+    report.resolved_at = Some(clock.unix_timestamp);
+    
+    emit!(ReportResolved {
+        report: report.key(),
+        target: report.target,
+        outcome,
+        resolved_by: authority,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
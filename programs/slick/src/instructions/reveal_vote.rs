@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// Reveal a secret ballot
+/// 
+/// Checks the choice and salt against the stored commitment and counts the
+/// vote. On ranked-choice polls `option_indices` is the ranking.
+/// 
+/// Validation:
+/// - Poll must be in its reveal window (after end_time, before reveal_end_time)
+/// - Vote must hold an unrevealed commitment
+/// - Choice and salt must hash to the commitment
+/// - Option indices must be valid for the poll mode
+/// 
+/// Events: PollVoted
+#[derive(Accounts)]
+pub struct RevealVote<'info> {
+    #[account(mut)]
+    pub poll: Account<'info, PollAccount>,
+    
+    #[account(
+        mut,
+        seeds = [VOTE_SEED, poll.key().as_ref(), voter.key().as_ref()],
+        bump,
+        has_one = poll,
+        has_one = voter
+    )]
+    pub vote: Account<'info, VoteAccount>,
+    
+    pub voter: Signer<'info>,
+}
+
+pub fn reveal_vote(
+    ctx: Context<RevealVote>,
+    option_indices: Vec<u8>,
+    salt: [u8; 32],
+) -> Result<()> {
+    let poll = &mut ctx.accounts.poll;
+    let vote = &mut ctx.accounts.vote;
+    let clock = Clock::get()?;
+    
+    let reveal_end_time = poll.reveal_end_time.ok_or(SocialError::PollModeMismatch)?;
+    require!(
+        clock.unix_timestamp >= poll.end_time && clock.unix_timestamp < reveal_end_time,
+        SocialError::NotInRevealWindow
+    );
+    
+    // Verify the choice against the commitment
+    let commitment = vote.commitment.ok_or(SocialError::NothingToReveal)?;
+    let revealed = hashv(&[
+        poll.key().as_ref(),
+        vote.voter.as_ref(),
+        &option_indices,
+        &salt,
+    ]);
+    require!(
+        revealed.to_bytes() == commitment,
+        SocialError::CommitmentMismatch
+    );
+    
+    // Validate option indices against the poll mode
+    let selections = poll.selection_mask(&option_indices)?;
+    let option_index = option_indices[0];
+    
+    vote.option_index = option_index;
+    vote.selections = selections;
+    vote.commitment = None;
+    if poll.mode == PollMode::RankedChoice {
+        vote.ranking = option_indices;
+    }
+    
+    // Count the revealed vote
+    poll.add_votes(vote.counted_options(), vote.weight)?;
+    poll.total_voters = poll.total_voters
+        .checked_add(1)
+        .ok_or(SocialError::ArithmeticOverflow)?;
+    poll.unrevealed_votes = poll.unrevealed_votes
+        .checked_sub(1)
+        .ok_or(SocialError::ArithmeticOverflow)?;
+    
+    emit!(PollVoted {
+        poll: poll.key(),
+        voter: vote.voter,
+        option_index,
+        selections,
+        weight: vote.weight,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// Revoke a poll vote
+/// 
+/// Removes the vote from the counts and closes the vote record, refunding
+/// rent to the voter. The voter may vote again afterwards. On token-weighted
+/// polls the escrowed tokens can then be reclaimed with `withdraw_vote_tokens`.
+/// 
+/// Validation:
+/// - Poll must allow vote changes
+/// - Poll must not have ended
+/// 
+/// Events: VoteRevoked
+#[derive(Accounts)]
+pub struct RevokeVote<'info> {
+    #[account(mut)]
+    pub poll: Account<'info, PollAccount>,
+    
+    #[account(
+        mut,
+        close = voter,
+        seeds = [VOTE_SEED, poll.key().as_ref(), voter.key().as_ref()],
+        bump,
+        has_one = poll,
+        has_one = voter
+    )]
+    pub vote: Account<'info, VoteAccount>,
+    
+    #[account(mut)]
+    pub voter: Signer<'info>,
+}
+
+pub fn revoke_vote(ctx: Context<RevokeVote>) -> Result<()> {
+    let poll = &mut ctx.accounts.poll;
+    let clock = Clock::get()?;
+    
+    require!(poll.allow_vote_change, SocialError::VoteChangeNotAllowed);
+    
+    // Check poll has not ended
+    require!(
+        clock.unix_timestamp < poll.end_time,
+        SocialError::PollEnded
+    );
+    
+    // Remove the vote from the counts
+    let vote = &ctx.accounts.vote;
+    poll.remove_votes(vote.counted_options(), vote.weight)?;
+    poll.total_voters = poll.total_voters
+        .checked_sub(1)
+        .ok_or(SocialError::ArithmeticOverflow)?;
+    
+    emit!(VoteRevoked {
+        poll: poll.key(),
+        voter: ctx.accounts.voter.key(),
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
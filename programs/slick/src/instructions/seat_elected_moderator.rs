@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// Seat an election winner as community moderator
+/// 
+/// Permissionless once the election is finalized. Creates the winner's
+/// moderator record with a term of `term_length` seconds from the end of
+/// voting, or renews an existing record whose term is elected or expired.
+/// Renewal never shortens a term, and moderators without a term limit keep
+/// it. Each winning seat can be claimed once, so a moderator removed
+/// mid-term stays removed.
+/// 
+/// Validation:
+/// - Poll must be a finalized election
+/// - Profile must be one of the winning options
+/// - Seat must not have been claimed already
+/// - Winner must still be a member of the community
+/// 
+/// Events: ModeratorElected
+#[derive(Accounts)]
+pub struct SeatElectedModerator<'info> {
+    #[account(mut)]
+    pub poll: Account<'info, PollAccount>,
+    
+    #[account(address = poll.community)]
+    pub community: Account<'info, CommunityAccount>,
+    
+    pub winner_profile: Account<'info, ProfileAccount>,
+    
+    #[account(
+        seeds = [MEMBERSHIP_SEED, community.key().as_ref(), winner_profile.owner.as_ref()],
+        bump
+    )]
+    pub membership: Account<'info, MembershipAccount>,
+    
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = MODERATOR_SIZE,
+        seeds = [MODERATOR_SEED, community.key().as_ref(), winner_profile.owner.as_ref()],
+        bump
+    )]
+    pub moderator: Account<'info, ModeratorAccount>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn seat_elected_moderator(ctx: Context<SeatElectedModerator>) -> Result<()> {
+    let poll = &mut ctx.accounts.poll;
+    let moderator = &mut ctx.accounts.moderator;
+    let winner = ctx.accounts.winner_profile.owner;
+    let clock = Clock::get()?;
+    
+    require!(poll.election_seats > 0, SocialError::NotAnElection);
+    require!(poll.is_finalized(), SocialError::PollNotFinalized);
+    
+    // Profile must be a winning option
+    let index = poll.option_profiles
+        .iter()
+        .position(|profile| *profile == ctx.accounts.winner_profile.key())
+        .ok_or(SocialError::NotElectionWinner)?;
+    require!(
+        poll.winners & (1 << index) != 0,
+        SocialError::NotElectionWinner
+    );
+    require!(
+        poll.seated_winners & (1 << index) == 0,
+        SocialError::SeatAlreadyClaimed
+    );
+    poll.seated_winners |= 1 << index;
+    
+    let term_ends_at = poll.results_at()
+        .checked_add(poll.term_length)
+        .ok_or(SocialError::ArithmeticOverflow)?;
+    
+    let expired = moderator.term_ends_at.is_some_and(|ends_at| clock.unix_timestamp >= ends_at);
+    if moderator.community == Pubkey::default() || expired {
+        // New term
+        moderator.community = ctx.accounts.community.key();
+        moderator.user = winner;
+        moderator.added_by = poll.key();
+        moderator.added_at = clock.unix_timestamp;
+        moderator.term_ends_at = Some(term_ends_at);
+    } else if let Some(current) = moderator.term_ends_at {
+        // Re-election extends the current term
+        moderator.term_ends_at = Some(current.max(term_ends_at));
+    }
+    
+    emit!(ModeratorElected {
+        community: ctx.accounts.community.key(),
+        moderator: winner,
+        poll: poll.key(),
+        term_ends_at,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// Set the minimums every governance proposal must meet
+/// 
+/// Governance is disabled until the creator sets a quorum of at least one
+/// voter; a quorum of 0 disables it again. Proposals already created keep
+/// the values they were created with.
+/// 
+/// Validation:
+/// - Only the community creator can change the minimums
+/// - Threshold must be at most 10000 basis points
+/// - Timelock must not be negative
+/// 
+/// Events: CommunityUpdated
+#[derive(Accounts)]
+pub struct SetGovernanceConfig<'info> {
+    #[account(
+        mut,
+        has_one = creator @ SocialError::NotCommunityCreator
+    )]
+    pub community: Account<'info, CommunityAccount>,
+    
+    pub creator: Signer<'info>,
+}
+
+pub fn set_governance_config(
+    ctx: Context<SetGovernanceConfig>,
+    min_quorum: u32,
+    min_threshold_bps: u16,
+    min_timelock: i64,
+) -> Result<()> {
+    require!(min_threshold_bps <= MAX_THRESHOLD_BPS, SocialError::InvalidThreshold);
+    require!(min_timelock >= 0, SocialError::InvalidTimelock);
+    
+    let community = &mut ctx.accounts.community;
+    let clock = Clock::get()?;
+    
+    community.min_governance_quorum = min_quorum;
+    community.min_governance_threshold_bps = min_threshold_bps;
+    community.min_governance_timelock = min_timelock;
+    
+    emit!(CommunityUpdated {
+        community: community.key(),
+        updated_by: ctx.accounts.creator.key(),
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
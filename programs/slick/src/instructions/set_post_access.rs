@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::{errors::*, events::*, state::*};
+
+/// Restrict a post's likes and comments to the author's subscribers
+/// 
+/// Validation:
+/// - Signer must be the post author (not anonymous)
+/// 
+/// Events: PostAccessSet
+#[derive(Accounts)]
+pub struct SetPostAccess<'info> {
+    #[account(
+        mut,
+        constraint = post.author == Some(author.key()) @ SocialError::NotPostAuthor
+    )]
+    pub post: Account<'info, PostAccount>,
+    
+    pub author: Signer<'info>,
+}
+
+pub fn set_post_access(ctx: Context<SetPostAccess>, subscribers_only: bool) -> Result<()> {
+    let post = &mut ctx.accounts.post;
+    let clock = Clock::get()?;
+    
+    post.subscribers_only = subscribers_only;
+    
+    emit!(PostAccessSet {
+        post: post.key(),
+        author: ctx.accounts.author.key(),
+        subscribers_only,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
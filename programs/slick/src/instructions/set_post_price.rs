@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::{errors::*, events::*, state::*};
+
+/// Set the price to unlock a post's content
+/// 
+/// A price of zero makes the post free again. Existing unlock receipts stay
+/// valid whatever the price.
+/// 
+/// Validation:
+/// - Signer must be the post author (not anonymous)
+/// 
+/// Events: PostPriceSet
+#[derive(Accounts)]
+pub struct SetPostPrice<'info> {
+    #[account(
+        mut,
+        constraint = post.author == Some(author.key()) @ SocialError::NotPostAuthor
+    )]
+    pub post: Account<'info, PostAccount>,
+    
+    pub author: Signer<'info>,
+}
+
+pub fn set_post_price(ctx: Context<SetPostPrice>, price_lamports: u64) -> Result<()> {
+    let post = &mut ctx.accounts.post;
+    let clock = Clock::get()?;
+    
+    post.price_lamports = price_lamports;
+    
+    emit!(PostPriceSet {
+        post: post.key(),
+        author: ctx.accounts.author.key(),
+        price_lamports,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
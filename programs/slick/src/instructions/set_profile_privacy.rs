@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, events::*, state::*};
+
+/// Make a profile private or public
+/// 
+/// Private profiles can only be followed through an approved follow request
+/// (see `request_follow`). Existing followers are kept either way.
+/// 
+/// Validation:
+/// - Only the profile owner can change it
+/// 
+/// Events: ProfileUpdated
+#[derive(Accounts)]
+pub struct SetProfilePrivacy<'info> {
+    #[account(
+        mut,
+        seeds = [PROFILE_SEED, owner.key().as_ref()],
+        bump,
+        has_one = owner
+    )]
+    pub profile: Account<'info, ProfileAccount>,
+    
+    pub owner: Signer<'info>,
+}
+
+pub fn set_profile_privacy(ctx: Context<SetProfilePrivacy>, requires_approval: bool) -> Result<()> {
+    let profile = &mut ctx.accounts.profile;
+    let clock = Clock::get()?;
+    
+    profile.requires_approval = requires_approval;
+    
+    emit!(ProfileUpdated {
+        profile: profile.key(),
+        owner: profile.owner,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::{errors::*, events::*, state::*};
+
+/// Configure how many reports auto-hide a post or comment
+/// 
+/// A threshold of 0 disables auto-hiding.
+/// 
+/// Validation:
+/// - Only the community creator can change the threshold
+/// 
+/// Events: CommunityUpdated
+#[derive(Accounts)]
+pub struct SetReportThreshold<'info> {
+    #[account(
+        mut,
+        has_one = creator @ SocialError::NotCommunityCreator
+    )]
+    pub community: Account<'info, CommunityAccount>,
+    
+    pub creator: Signer<'info>,
+}
+
+pub fn set_report_threshold(ctx: Context<SetReportThreshold>, threshold: u32) -> Result<()> {
+    let community = &mut ctx.accounts.community;
+    let clock = Clock::get()?;
+    
+    community.report_threshold = threshold;
+    
+    emit!(CommunityUpdated {
+        community: community.key(),
+        updated_by: ctx.accounts.creator.key(),
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// Publish the subscription tiers a profile offers
+/// 
+/// Replaces any existing tiers; an empty list stops new subscriptions.
+/// Existing subscriptions keep their expiry.
+/// 
+/// Validation:
+/// - Only the profile owner can set tiers
+/// - At most MAX_SUBSCRIPTION_TIERS tiers
+/// - Each tier needs a positive price and period
+/// 
+/// Events: SubscriptionTiersSet
+#[derive(Accounts)]
+pub struct SetSubscriptionTiers<'info> {
+    #[account(
+        mut,
+        seeds = [PROFILE_SEED, owner.key().as_ref()],
+        bump,
+        has_one = owner
+    )]
+    pub profile: Account<'info, ProfileAccount>,
+    
+    pub owner: Signer<'info>,
+}
+
+pub fn set_subscription_tiers(
+    ctx: Context<SetSubscriptionTiers>,
+    tiers: Vec<SubscriptionTier>,
+) -> Result<()> {
+    require!(
+        tiers.len() <= MAX_SUBSCRIPTION_TIERS,
+        SocialError::TooManySubscriptionTiers
+    );
+    require!(
+        tiers.iter().all(|tier| tier.price_lamports > 0 && tier.period > 0),
+        SocialError::InvalidSubscriptionTier
+    );
+    
+    let profile = &mut ctx.accounts.profile;
+    let clock = Clock::get()?;
+    
+    let tier_count = tiers.len() as u8;
+    profile.subscription_tiers = tiers;
+    
+    emit!(SubscriptionTiersSet {
+        profile: profile.key(),
+        owner: profile.owner,
+        tier_count,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,242 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::{self, CloseAccount, Token, TokenAccount, Transfer};
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// Pay out a poll prize
+/// 
+/// Permissionless once the poll is finalized. The prize is split evenly
+/// between the winning profiles' owners, with any remainder going to the
+/// first winner. A poll with no winners, including one that missed its
+/// quorum, refunds the funder. A winner who cannot receive their share
+/// forfeits it to the funder rather than blocking the others: on SOL prizes,
+/// when the payout would leave their wallet below rent exemption; on token
+/// prizes, when their associated token account does not exist or cannot
+/// receive the prize. The prize account
+/// (and vault) are closed and their rent returned to the funder.
+/// 
+/// Remaining accounts, for each winning option in index order:
+/// - The winning profile
+/// - Its owner's wallet (SOL prizes) or associated token account for the
+///   prize mint
+/// 
+/// Validation:
+/// - Poll must be finalized
+/// - Token prizes must pass the prize vault, funder token account (refunds
+///   only) and token program
+/// - Remaining accounts must match the winners
+/// 
+/// Events: PrizeSettled
+#[derive(Accounts)]
+pub struct SettlePrizePoll<'info> {
+    #[account(mut)]
+    pub poll: Account<'info, PollAccount>,
+    
+    #[account(
+        mut,
+        close = funder,
+        has_one = poll,
+        has_one = funder,
+        seeds = [PRIZE_SEED, poll.key().as_ref()],
+        bump
+    )]
+    pub prize: Account<'info, PrizeAccount>,
+    
+    /// CHECK: Prize funder, verified by has_one on the prize
+    #[account(mut)]
+    pub funder: UncheckedAccount<'info>,
+    
+    /// Required for token prizes
+    #[account(
+        mut,
+        seeds = [PRIZE_VAULT_SEED, poll.key().as_ref()],
+        bump,
+        token::authority = prize
+    )]
+    pub prize_vault: Option<Account<'info, TokenAccount>>,
+    
+    /// Required to refund token prizes with no winner
+    #[account(
+        mut,
+        token::authority = funder
+    )]
+    pub funder_token_account: Option<Account<'info, TokenAccount>>,
+    
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+pub fn settle_prize_poll<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SettlePrizePoll<'info>>,
+) -> Result<()> {
+    let poll = &ctx.accounts.poll;
+    let prize = &ctx.accounts.prize;
+    let clock = Clock::get()?;
+    
+    require!(poll.is_finalized(), SocialError::PollNotFinalized);
+    
+    // Resolve each winner's profile and payout account
+    let winning_options: Vec<usize> = (0..poll.option_count())
+        .filter(|&index| poll.winners & (1 << index) != 0)
+        .collect();
+    require!(
+        ctx.remaining_accounts.len() == winning_options.len() * 2,
+        SocialError::PrizeAccountsMismatch
+    );
+    
+    let mut winners = Vec::with_capacity(winning_options.len());
+    for (&option, accounts) in winning_options.iter().zip(ctx.remaining_accounts.chunks(2)) {
+        let (profile_info, recipient) = (&accounts[0], &accounts[1]);
+        
+        require_keys_eq!(
+            profile_info.key(),
+            poll.option_profiles[option],
+            SocialError::PrizeAccountsMismatch
+        );
+        require_keys_eq!(*profile_info.owner, *ctx.program_id, SocialError::PrizeAccountsMismatch);
+        let profile = ProfileAccount::try_deserialize(&mut &profile_info.data.borrow()[..])
+            .map_err(|_| SocialError::PrizeAccountsMismatch)?;
+        
+        // Whether the recipient can take the payout (SOL wallets are checked
+        // once the payouts are known); the token account is pinned to the
+        // winner's ATA so nobody can forfeit a share for them
+        let payable = match prize.mint {
+            None => {
+                require_keys_eq!(recipient.key(), profile.owner, SocialError::PrizeAccountsMismatch);
+                true
+            }
+            Some(mint) => {
+                require_keys_eq!(
+                    recipient.key(),
+                    get_associated_token_address(&profile.owner, &mint),
+                    SocialError::PrizeAccountsMismatch
+                );
+                *recipient.owner == token::ID
+                    && TokenAccount::try_deserialize(&mut &recipient.data.borrow()[..])
+                        .is_ok_and(|account| {
+                            account.mint == mint && account.owner == profile.owner && !account.is_frozen()
+                        })
+            }
+        };
+        
+        winners.push((profile.owner, recipient, payable));
+    }
+    
+    // Token prizes pay out the whole vault, so tokens sent to it later
+    // cannot stop it from closing
+    let amount = match prize.mint {
+        None => prize.amount,
+        Some(_) => ctx.accounts.prize_vault
+            .as_ref()
+            .ok_or(SocialError::PrizeAccountsMismatch)?
+            .amount,
+    };
+    
+    // Split evenly; the first winner takes any remainder
+    let amount_per_winner = amount
+        .checked_div(winners.len() as u64)
+        .unwrap_or_default();
+    let remainder = amount - amount_per_winner * winners.len() as u64;
+    let payouts: Vec<u64> = (0..winners.len())
+        .map(|index| if index == 0 { amount_per_winner + remainder } else { amount_per_winner })
+        .collect();
+    
+    // A wallet left below rent exemption would fail the whole settlement
+    if prize.mint.is_none() {
+        let rent = Rent::get()?;
+        for ((_, recipient, payable), &payout) in winners.iter_mut().zip(payouts.iter()) {
+            let balance = recipient.lamports()
+                .checked_add(payout)
+                .ok_or(SocialError::ArithmeticOverflow)?;
+            *payable = rent.is_exempt(balance, recipient.data_len());
+        }
+    }
+    
+    let refunded = if winners.is_empty() {
+        amount
+    } else {
+        winners.iter()
+            .zip(payouts.iter())
+            .filter(|((_, _, payable), _)| !payable)
+            .map(|(_, &payout)| payout)
+            .sum()
+    };
+    
+    match prize.mint {
+        None => {
+            // Lamports come straight out of the prize account; the refund,
+            // if any, is returned with its rent when it closes
+            let prize_info = prize.to_account_info();
+            for ((_, recipient, payable), &payout) in winners.iter().zip(payouts.iter()) {
+                if *payable {
+                    **prize_info.try_borrow_mut_lamports()? -= payout;
+                    **recipient.try_borrow_mut_lamports()? += payout;
+                }
+            }
+        }
+        Some(mint) => {
+            let vault = ctx.accounts.prize_vault
+                .as_ref()
+                .ok_or(SocialError::PrizeAccountsMismatch)?;
+            let token_program = ctx.accounts.token_program
+                .as_ref()
+                .ok_or(SocialError::PrizeAccountsMismatch)?;
+            
+            let poll_key = poll.key();
+            let bump = [ctx.bumps.prize];
+            let signer_seeds: &[&[&[u8]]] = &[&[PRIZE_SEED, poll_key.as_ref(), &bump]];
+            
+            let transfer = |to: AccountInfo<'info>, amount: u64| {
+                token::transfer(
+                    CpiContext::new_with_signer(
+                        token_program.to_account_info(),
+                        Transfer {
+                            from: vault.to_account_info(),
+                            to,
+                            authority: prize.to_account_info(),
+                        },
+                        signer_seeds,
+                    ),
+                    amount,
+                )
+            };
+            
+            for ((_, recipient, payable), &payout) in winners.iter().zip(payouts.iter()) {
+                if *payable {
+                    transfer((*recipient).clone(), payout)?;
+                }
+            }
+            
+            if refunded > 0 {
+                let funder_token_account = ctx.accounts.funder_token_account
+                    .as_ref()
+                    .ok_or(SocialError::PrizeAccountsMismatch)?;
+                require_keys_eq!(funder_token_account.mint, mint, SocialError::PrizeAccountsMismatch);
+                transfer(funder_token_account.to_account_info(), refunded)?;
+            }
+            
+            // Close the vault and refund its rent
+            token::close_account(CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                CloseAccount {
+                    account: vault.to_account_info(),
+                    destination: ctx.accounts.funder.to_account_info(),
+                    authority: prize.to_account_info(),
+                },
+                signer_seeds,
+            ))?;
+        }
+    }
+    
+    emit!(PrizeSettled {
+        poll: poll.key(),
+        prize: prize.key(),
+        winners: winners.iter().map(|(owner, _, _)| *owner).collect(),
+        amount_per_winner,
+        refunded,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    ctx.accounts.poll.has_prize = false;
+    
+    Ok(())
+}
@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::{constants::*, errors::*, events::*, state::*, utils::*};
+
+/// Subscribe to a creator, or extend an existing subscription
+/// 
+/// Pays one period of the chosen tier to the creator. A new or lapsed
+/// subscription runs from now; an active one is extended from its current
+/// expiry, at the newly chosen tier. The subscriber passes the price they
+/// expect to pay so a tier change cannot overcharge them.
+/// 
+/// Validation:
+/// - Tier must exist and cost `price_lamports`
+/// - Subscriber cannot subscribe to themselves
+/// - Creator must not have blocked the subscriber
+/// 
+/// Events: Subscribed
+#[derive(Accounts)]
+pub struct Subscribe<'info> {
+    #[account(
+        seeds = [PROFILE_SEED, creator.key().as_ref()],
+        bump
+    )]
+    pub creator_profile: Account<'info, ProfileAccount>,
+    
+    /// CHECK: Creator wallet, receives the payment
+    #[account(mut)]
+    pub creator: UncheckedAccount<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = subscriber,
+        space = SUBSCRIPTION_SIZE,
+        seeds = [SUBSCRIPTION_SEED, creator.key().as_ref(), subscriber.key().as_ref()],
+        bump
+    )]
+    pub subscription: Account<'info, SubscriptionAccount>,
+    
+    /// CHECK: Block PDA from the creator to the subscriber, verified in handler
+    pub block: Option<UncheckedAccount<'info>>,
+    
+    #[account(mut)]
+    pub subscriber: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn subscribe(ctx: Context<Subscribe>, tier: u8, price_lamports: u64) -> Result<()> {
+    let creator = ctx.accounts.creator.key();
+    let subscriber = ctx.accounts.subscriber.key();
+    let clock = Clock::get()?;
+    
+    let offered = *ctx.accounts.creator_profile.subscription_tiers
+        .get(tier as usize)
+        .ok_or(SocialError::SubscriptionTierNotFound)?;
+    require!(
+        offered.price_lamports == price_lamports,
+        SocialError::SubscriptionPriceChanged
+    );
+    require!(creator != subscriber, SocialError::CannotSubscribeToSelf);
+    
+    // Check the creator has not blocked the subscriber
+    require_not_blocked(
+        ctx.accounts.block.as_deref(),
+        &creator,
+        &subscriber,
+        ctx.program_id,
+    )?;
+    
+    // Pay the creator
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.subscriber.to_account_info(),
+                to: ctx.accounts.creator.to_account_info(),
+            },
+        ),
+        price_lamports,
+    )?;
+    
+    let subscription = &mut ctx.accounts.subscription;
+    
+    // New or lapsed subscriptions start now
+    if !subscription.is_active(clock.unix_timestamp) {
+        subscription.creator = creator;
+        subscription.subscriber = subscriber;
+        subscription.started_at = clock.unix_timestamp;
+        subscription.expires_at = clock.unix_timestamp;
+    }
+    
+    subscription.tier = tier;
+    subscription.expires_at = subscription.expires_at
+        .checked_add(offered.period)
+        .ok_or(SocialError::ArithmeticOverflow)?;
+    subscription.total_paid = subscription.total_paid
+        .checked_add(price_lamports)
+        .ok_or(SocialError::ArithmeticOverflow)?;
+    
+    emit!(Subscribed {
+        subscription: subscription.key(),
+        creator,
+        subscriber,
+        tier,
+        price_lamports,
+        expires_at: subscription.expires_at,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
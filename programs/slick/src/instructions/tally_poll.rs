@@ -0,0 +1,132 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// Tally a ranked-choice poll by instant-runoff
+/// 
+/// Permissionless. Remaining accounts are the poll's vote accounts (writable).
+/// Each call counts the ballots it is given toward the current round; once
+/// every ballot has been counted the round closes, and the same ballots are
+/// passed again for the next round until a winner is found. Ballots already
+/// counted in the current round are skipped, so batches may overlap.
+/// 
+/// Validation:
+/// - Poll must be a ranked-choice poll
+/// - Poll must have ended, including any reveal window
+/// - Tally must not be complete
+/// - Every remaining account must be a vote on this poll
+/// 
+/// Events: PollRoundTallied (per closed round), PollTallied
+#[derive(Accounts)]
+pub struct TallyPoll<'info> {
+    pub poll: Account<'info, PollAccount>,
+    
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = POLL_RESULT_SIZE,
+        seeds = [POLL_RESULT_SEED, poll.key().as_ref()],
+        bump
+    )]
+    pub poll_result: Account<'info, PollResultAccount>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn tally_poll(ctx: Context<TallyPoll>) -> Result<()> {
+    let poll = &ctx.accounts.poll;
+    let result = &mut ctx.accounts.poll_result;
+    let clock = Clock::get()?;
+    
+    require!(
+        poll.mode == PollMode::RankedChoice,
+        SocialError::PollModeMismatch
+    );
+    
+    // Check poll has ended (including any reveal window)
+    require!(
+        clock.unix_timestamp >= poll.results_at(),
+        SocialError::PollNotEnded
+    );
+    
+    // First call sets up the tally
+    if result.poll == Pubkey::default() {
+        result.poll = poll.key();
+        result.current_counts = vec![0; poll.option_count()];
+    }
+    
+    require!(result.completed_at.is_none(), SocialError::TallyComplete);
+    
+    // A poll nobody voted in closes without a winner
+    if poll.total_voters == 0 {
+        close_round(result, poll.key(), clock.unix_timestamp);
+    }
+    
+    for vote_info in ctx.remaining_accounts {
+        if result.completed_at.is_some() {
+            break;
+        }
+        
+        require_keys_eq!(*vote_info.owner, *ctx.program_id, SocialError::InvalidVoteAccount);
+        let mut vote = VoteAccount::try_deserialize(&mut &vote_info.data.borrow()[..])
+            .map_err(|_| SocialError::InvalidVoteAccount)?;
+        require_keys_eq!(vote.poll, poll.key(), SocialError::InvalidVoteAccount);
+        
+        // Skip ballots already counted this round, and unrevealed secret ballots
+        if vote.tallied_round != result.round || vote.commitment.is_some() {
+            continue;
+        }
+        
+        // Count toward the highest-ranked option still standing
+        match vote.current_preference(result.eliminated) {
+            Some(option) => {
+                let count = &mut result.current_counts[option as usize];
+                *count = count.checked_add(1).ok_or(SocialError::ArithmeticOverflow)?;
+            }
+            None => {
+                result.exhausted = result.exhausted
+                    .checked_add(1)
+                    .ok_or(SocialError::ArithmeticOverflow)?;
+            }
+        }
+        
+        vote.tallied_round = vote.tallied_round
+            .checked_add(1)
+            .ok_or(SocialError::ArithmeticOverflow)?;
+        vote.try_serialize(&mut &mut vote_info.data.borrow_mut()[..])?;
+        
+        result.processed_votes = result.processed_votes
+            .checked_add(1)
+            .ok_or(SocialError::ArithmeticOverflow)?;
+        
+        if result.processed_votes == poll.total_voters {
+            close_round(result, poll.key(), clock.unix_timestamp);
+        }
+    }
+    
+    if result.completed_at.is_some() {
+        emit!(PollTallied {
+            poll: poll.key(),
+            winner: result.winner,
+            rounds: result.round,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+    
+    Ok(())
+}
+
+fn close_round(result: &mut PollResultAccount, poll: Pubkey, now: i64) {
+    let round = result.round;
+    let eliminated = result.complete_round(now);
+    
+    emit!(PollRoundTallied {
+        poll,
+        round,
+        counts: result.rounds.last().cloned().unwrap_or_default(),
+        eliminated,
+        timestamp: now,
+    });
+}
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, instructions::{require_valid_memo, send_tip}, state::*};
+
+/// Tip a commenter
+/// 
+/// Same rules as `tip_post`, including the optional memo, credited to the
+/// comment and the commenter's profile.
+/// 
+/// Validation:
+/// - Amount must match FIXED_TIP_AMOUNT (0.002 SOL)
+/// - Memo (if any) must be at most MAX_TIP_MEMO_LEN bytes
+/// - Tipper cannot tip their own comment
+/// - Post must not be locked
+/// - Commenter must not have blocked the tipper
+/// 
+/// Events: CommentTipped
+#[derive(Accounts)]
+pub struct TipComment<'info> {
+    #[account(address = comment.post)]
+    pub post: Account<'info, PostAccount>,
+    
+    #[account(mut)]
+    pub comment: Account<'info, CommentAccount>,
+    
+    /// CHECK: This is the commenter who receives the tip
+    #[account(
+        mut,
+        address = comment.commenter @ SocialError::InvalidTipAmount
+    )]
+    pub recipient: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        seeds = [PROFILE_SEED, recipient.key().as_ref()],
+        bump
+    )]
+    pub recipient_profile: Account<'info, ProfileAccount>,
+    
+    /// CHECK: Block PDA from the commenter to the tipper, verified in handler
+    pub block: Option<UncheckedAccount<'info>>,
+    
+    #[account(mut)]
+    pub tipper: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn tip_comment(ctx: Context<TipComment>, amount: u64, memo: Option<String>) -> Result<()> {
+    require_valid_memo(memo.as_ref())?;
+    
+    let clock = Clock::get()?;
+    
+    // Check post is not locked
+    require!(
+        !ctx.accounts.post.is_locked(clock.unix_timestamp),
+        SocialError::PostLocked
+    );
+    
+    send_tip(
+        &ctx.accounts.tipper,
+        &ctx.accounts.recipient,
+        &mut ctx.accounts.recipient_profile,
+        ctx.accounts.block.as_deref(),
+        &ctx.accounts.system_program,
+        ctx.program_id,
+        amount,
+    )?;
+    
+    let comment = &mut ctx.accounts.comment;
+    comment.total_tip_lamports = comment.total_tip_lamports
+        .checked_add(amount)
+        .ok_or(SocialError::ArithmeticOverflow)?;
+    
+    emit!(CommentTipped {
+        comment: comment.key(),
+        post: comment.post,
+        tipper: ctx.accounts.tipper.key(),
+        recipient: ctx.accounts.recipient.key(),
+        amount,
+        memo,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
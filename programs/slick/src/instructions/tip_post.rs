@@ -1,16 +1,21 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program;
-use crate::{constants::*, errors::*, events::*, state::*};
+use crate::{constants::*, errors::*, events::*, state::*, utils::*};
 
 /// Tip a post creator
 /// 
-/// This is a direct SOL transfer from tipper to post author.
-/// The program tracks the total tips received on-chain.
+/// This is a direct SOL transfer from tipper to post author, with an
+/// optional short memo. The program tracks the total tips received on-chain,
+/// on the post and on the author's profile, and keeps a running total per
+/// tipper for supporter leaderboards.
 /// 
 /// Validation:
 /// - Post must not be anonymous (must have an author)
 /// - Amount must match FIXED_TIP_AMOUNT (0.002 SOL)
+/// - Memo (if any) must be at most MAX_TIP_MEMO_LEN bytes
 /// - Tipper cannot tip their own post
+/// - Post must not be locked
+/// - Post author must not have blocked the tipper
 /// 
 /// Events: PostTipped
 #[derive(Accounts)]
@@ -26,38 +31,50 @@ pub struct TipPost<'info> {
     )]
     pub recipient: AccountInfo<'info>,
     
+    #[account(
+        mut,
+        seeds = [PROFILE_SEED, recipient.key().as_ref()],
+        bump
+    )]
+    pub recipient_profile: Account<'info, ProfileAccount>,
+    
+    #[account(
+        init_if_needed,
+        payer = tipper,
+        space = TIP_RECORD_SIZE,
+        seeds = [TIP_RECORD_SEED, post.key().as_ref(), tipper.key().as_ref()],
+        bump
+    )]
+    pub tip_record: Account<'info, TipRecordAccount>,
+    
+    /// CHECK: Block PDA from the post author to the tipper, verified in handler
+    pub block: Option<UncheckedAccount<'info>>,
+    
     #[account(mut)]
     pub tipper: Signer<'info>,
     
     pub system_program: Program<'info, System>,
 }
 
-pub fn tip_post(ctx: Context<TipPost>, amount: u64) -> Result<()> {
-    // Validate amount is the fixed tip amount
-    require!(
-        amount == FIXED_TIP_AMOUNT,
-        SocialError::InvalidTipAmount
-    );
+pub fn tip_post(ctx: Context<TipPost>, amount: u64, memo: Option<String>) -> Result<()> {
+    require_valid_memo(memo.as_ref())?;
     
     let post = &mut ctx.accounts.post;
+    let clock = Clock::get()?;
     
-    // Check not tipping own post
-    if let Some(author) = post.author {
-        require!(
-            author != ctx.accounts.tipper.key(),
-            SocialError::CannotLikeOwnPost // Reuse error for similar logic
-        );
-    }
+    // Check post is not locked
+    require!(
+        !post.is_locked(clock.unix_timestamp),
+        SocialError::PostLocked
+    );
     
-    // Transfer SOL from tipper to post author
-    system_program::transfer(
-        CpiContext::new(
-            ctx.accounts.system_program.to_account_info(),
-            system_program::Transfer {
-                from: ctx.accounts.tipper.to_account_info(),
-                to: ctx.accounts.recipient.to_account_info(),
-            },
-        ),
+    send_tip(
+        &ctx.accounts.tipper,
+        &ctx.accounts.recipient,
+        &mut ctx.accounts.recipient_profile,
+        ctx.accounts.block.as_deref(),
+        &ctx.accounts.system_program,
+        ctx.program_id,
         amount,
     )?;
     
@@ -66,15 +83,86 @@ pub fn tip_post(ctx: Context<TipPost>, amount: u64) -> Result<()> {
         .checked_add(amount)
         .ok_or(SocialError::ArithmeticOverflow)?;
     
-    let clock = Clock::get()?;
+    // Accumulate the tipper's running total for this post
+    let tip_record = &mut ctx.accounts.tip_record;
+    if tip_record.tips_count == 0 {
+        tip_record.post = post.key();
+        tip_record.tipper = ctx.accounts.tipper.key();
+        tip_record.first_tipped_at = clock.unix_timestamp;
+    }
+    tip_record.total_lamports = tip_record.total_lamports
+        .checked_add(amount)
+        .ok_or(SocialError::ArithmeticOverflow)?;
+    tip_record.tips_count = tip_record.tips_count
+        .checked_add(1)
+        .ok_or(SocialError::ArithmeticOverflow)?;
+    tip_record.last_tipped_at = clock.unix_timestamp;
     
     emit!(PostTipped {
         post: post.key(),
         tipper: ctx.accounts.tipper.key(),
         recipient: ctx.accounts.recipient.key(),
         amount,
+        memo,
         timestamp: clock.unix_timestamp,
     });
     
     Ok(())
 }
+
+/// Fails if a tip memo exceeds MAX_TIP_MEMO_LEN
+pub(crate) fn require_valid_memo(memo: Option<&String>) -> Result<()> {
+    require!(
+        memo.is_none_or(|memo| memo.len() <= MAX_TIP_MEMO_LEN),
+        SocialError::TipMemoTooLong
+    );
+    
+    Ok(())
+}
+
+/// Validates a tip and transfers it from the tipper to the recipient,
+/// crediting the recipient's lifetime tip total
+/// 
+/// Shared by `tip_post`, `tip_comment` and `tip_profile`.
+pub(crate) fn send_tip<'info>(
+    tipper: &Signer<'info>,
+    recipient: &AccountInfo<'info>,
+    recipient_profile: &mut Account<'info, ProfileAccount>,
+    block: Option<&AccountInfo<'info>>,
+    system_program: &Program<'info, System>,
+    program_id: &Pubkey,
+    amount: u64,
+) -> Result<()> {
+    // Validate amount is the fixed tip amount
+    require!(
+        amount == FIXED_TIP_AMOUNT,
+        SocialError::InvalidTipAmount
+    );
+    
+    // Check not tipping yourself
+    require!(
+        recipient.key() != tipper.key(),
+        SocialError::CannotTipSelf
+    );
+    
+    // Check the recipient has not blocked the tipper
+    require_not_blocked(block, &recipient.key(), &tipper.key(), program_id)?;
+    
+    // Transfer SOL from tipper to recipient
+    system_program::transfer(
+        CpiContext::new(
+            system_program.to_account_info(),
+            system_program::Transfer {
+                from: tipper.to_account_info(),
+                to: recipient.clone(),
+            },
+        ),
+        amount,
+    )?;
+    
+    recipient_profile.total_tips_received = recipient_profile.total_tips_received
+        .checked_add(amount)
+        .ok_or(SocialError::ArithmeticOverflow)?;
+    
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, instructions::{require_valid_memo, send_tip}, state::*};
+
+/// Tip a creator directly, without a post
+/// 
+/// Same rules as `tip_post`, including the optional memo, credited to the
+/// creator's profile.
+/// 
+/// Validation:
+/// - Amount must match FIXED_TIP_AMOUNT (0.002 SOL)
+/// - Memo (if any) must be at most MAX_TIP_MEMO_LEN bytes
+/// - Tipper cannot tip themselves
+/// - Profile owner must not have blocked the tipper
+/// 
+/// Events: ProfileTipped
+#[derive(Accounts)]
+pub struct TipProfile<'info> {
+    #[account(
+        mut,
+        seeds = [PROFILE_SEED, recipient.key().as_ref()],
+        bump
+    )]
+    pub profile: Account<'info, ProfileAccount>,
+    
+    /// CHECK: This is the profile owner who receives the tip
+    #[account(
+        mut,
+        address = profile.owner @ SocialError::InvalidTipAmount
+    )]
+    pub recipient: AccountInfo<'info>,
+    
+    /// CHECK: Block PDA from the profile owner to the tipper, verified in handler
+    pub block: Option<UncheckedAccount<'info>>,
+    
+    #[account(mut)]
+    pub tipper: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn tip_profile(ctx: Context<TipProfile>, amount: u64, memo: Option<String>) -> Result<()> {
+    require_valid_memo(memo.as_ref())?;
+    
+    let clock = Clock::get()?;
+    
+    send_tip(
+        &ctx.accounts.tipper,
+        &ctx.accounts.recipient,
+        &mut ctx.accounts.profile,
+        ctx.accounts.block.as_deref(),
+        &ctx.accounts.system_program,
+        ctx.program_id,
+        amount,
+    )?;
+    
+    emit!(ProfileTipped {
+        profile: ctx.accounts.profile.key(),
+        tipper: ctx.accounts.tipper.key(),
+        recipient: ctx.accounts.recipient.key(),
+        amount,
+        memo,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, events::*, state::*};
+
+/// Unblock a user
+/// 
+/// Follows removed by the block are not restored.
+/// 
+/// Validation:
+/// - Must currently be blocking the user
+/// - Only the blocker can unblock
+/// 
+/// Events: UserUnblocked
+#[derive(Accounts)]
+pub struct UnblockUser<'info> {
+    #[account(
+        seeds = [PROFILE_SEED, owner.key().as_ref()],
+        bump,
+        has_one = owner
+    )]
+    pub blocker_profile: Account<'info, ProfileAccount>,
+    
+    pub blocked_profile: Account<'info, ProfileAccount>,
+    
+    #[account(
+        mut,
+        close = owner,
+        seeds = [BLOCK_SEED, blocker_profile.key().as_ref(), blocked_profile.key().as_ref()],
+        bump
+    )]
+    pub block: Account<'info, BlockAccount>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+pub fn unblock_user(ctx: Context<UnblockUser>) -> Result<()> {
+    let clock = Clock::get()?;
+    
+    emit!(UserUnblocked {
+        blocker: ctx.accounts.block.blocker,
+        unblocked: ctx.accounts.block.blocked,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// Unlock a previously locked post
+/// 
+/// Validation:
+/// - Signer must be the post author or a community moderator
+/// - Post must be locked
+/// 
+/// Events: PostUnlocked
+#[derive(Accounts)]
+pub struct UnlockPost<'info> {
+    #[account(mut)]
+    pub post: Account<'info, PostAccount>,
+    
+    #[account(
+        constraint = community.key() == post.community @ SocialError::PostNotInCommunity
+    )]
+    pub community: Option<Account<'info, CommunityAccount>>,
+    
+    #[account(
+        seeds = [MODERATOR_SEED, post.community.as_ref(), authority.key().as_ref()],
+        bump
+    )]
+    pub moderator: Option<Account<'info, ModeratorAccount>>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn unlock_post(ctx: Context<UnlockPost>) -> Result<()> {
+    let post = &mut ctx.accounts.post;
+    let authority = ctx.accounts.authority.key();
+    let clock = Clock::get()?;
+    
+    // Check signer is the author or a moderator
+    let is_author = post.author == Some(authority);
+    let is_moderator = ctx.accounts.community
+        .as_ref()
+        .is_some_and(|community| {
+            community.is_moderator(&authority, ctx.accounts.moderator.as_deref(), clock.unix_timestamp)
+        });
+    require!(
+        is_author || is_moderator,
+        SocialError::NotAuthorOrModerator
+    );
+    
+    require!(post.locked, SocialError::PostNotLocked);
+    
+    post.locked = false;
+    post.locked_until = None;
+    
+    emit!(PostUnlocked {
+        post: post.key(),
+        unlocked_by: authority,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// Unpin a post from a community
+/// 
+/// Validation:
+/// - Signer must be the community creator or a moderator
+/// - Post must currently be pinned
+/// 
+/// Events: PostUnpinned
+#[derive(Accounts)]
+pub struct UnpinPost<'info> {
+    #[account(mut)]
+    pub community: Account<'info, CommunityAccount>,
+    
+    #[account(
+        seeds = [MODERATOR_SEED, community.key().as_ref(), authority.key().as_ref()],
+        bump
+    )]
+    pub moderator: Option<Account<'info, ModeratorAccount>>,
+    
+    pub post: Account<'info, PostAccount>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn unpin_post(ctx: Context<UnpinPost>) -> Result<()> {
+    let community = &mut ctx.accounts.community;
+    let post_key = ctx.accounts.post.key();
+    let authority = ctx.accounts.authority.key();
+    let clock = Clock::get()?;
+    
+    // Check signer can moderate
+    require!(
+        community.is_moderator(&authority, ctx.accounts.moderator.as_deref(), clock.unix_timestamp),
+        SocialError::NotModerator
+    );
+    
+    let position = community.pinned_posts
+        .iter()
+        .position(|pinned| *pinned == post_key)
+        .ok_or(SocialError::PostNotPinned)?;
+    community.pinned_posts.remove(position);
+    
+    emit!(PostUnpinned {
+        post: post_key,
+        target: community.key(),
+        unpinned_by: authority,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
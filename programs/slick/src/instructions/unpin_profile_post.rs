@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// Unpin a post from your profile
+/// 
+/// Validation:
+/// - Only the profile owner can unpin
+/// - Post must currently be pinned
+/// 
+/// Events: PostUnpinned
+#[derive(Accounts)]
+pub struct UnpinProfilePost<'info> {
+    #[account(
+        mut,
+        seeds = [PROFILE_SEED, owner.key().as_ref()],
+        bump,
+        has_one = owner
+    )]
+    pub profile: Account<'info, ProfileAccount>,
+    
+    pub post: Account<'info, PostAccount>,
+    
+    pub owner: Signer<'info>,
+}
+
+pub fn unpin_profile_post(ctx: Context<UnpinProfilePost>) -> Result<()> {
+    let profile = &mut ctx.accounts.profile;
+    let post_key = ctx.accounts.post.key();
+    let clock = Clock::get()?;
+    
+    let position = profile.pinned_posts
+        .iter()
+        .position(|pinned| *pinned == post_key)
+        .ok_or(SocialError::PostNotPinned)?;
+    profile.pinned_posts.remove(position);
+    
+    emit!(PostUnpinned {
+        post: post_key,
+        target: profile.key(),
+        unpinned_by: profile.owner,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -3,11 +3,19 @@ use crate::{constants::*, errors::*, events::*, state::*};
 
 /// Vote on a poll
 /// 
+/// `vote_poll` selects a single option; `vote_poll_multi` selects several
+/// on multi-choice and approval polls; `vote_poll_ranked` submits options in
+/// preference order on ranked-choice polls.
+/// 
 /// Validation:
-/// - Voter must be a member of the community
+/// - Voter must be a member of the community, for at least the poll's
+///   minimum membership age
 /// - Voter can only vote once per poll
-/// - Option index must be valid
-/// - Poll must not have ended
+/// - Option indices must be valid and distinct
+/// - Selection count must not exceed the poll's limit
+/// - Ranked ballots only on ranked-choice polls, and vice versa
+/// - Not on secret ballot polls (see `commit_vote`)
+/// - Poll must have started and not ended
 /// 
 /// Events: PollVoted
 #[derive(Accounts)]
@@ -37,38 +45,105 @@ pub struct VotePoll<'info> {
 }
 
 pub fn vote_poll(ctx: Context<VotePoll>, option_index: u8) -> Result<()> {
-    let poll = &mut ctx.accounts.poll;
-    let vote = &mut ctx.accounts.vote;
+    let accounts = ctx.accounts;
+    record_vote(
+        &mut accounts.poll,
+        &mut accounts.vote,
+        &accounts.membership,
+        accounts.voter.key(),
+        vec![option_index],
+        false,
+        None,
+    )
+}
+
+/// Vote for several options at once (multi-choice and approval polls)
+pub fn vote_poll_multi(ctx: Context<VotePoll>, option_indices: Vec<u8>) -> Result<()> {
+    let accounts = ctx.accounts;
+    record_vote(
+        &mut accounts.poll,
+        &mut accounts.vote,
+        &accounts.membership,
+        accounts.voter.key(),
+        option_indices,
+        false,
+        None,
+    )
+}
+
+/// Rank options in order of preference (ranked-choice polls)
+pub fn vote_poll_ranked(ctx: Context<VotePoll>, ranking: Vec<u8>) -> Result<()> {
+    let accounts = ctx.accounts;
+    record_vote(
+        &mut accounts.poll,
+        &mut accounts.vote,
+        &accounts.membership,
+        accounts.voter.key(),
+        ranking,
+        true,
+        None,
+    )
+}
+
+/// Records a vote with the given weight (`None` for one-member-one-vote polls)
+pub(crate) fn record_vote(
+    poll: &mut Account<PollAccount>,
+    vote: &mut Account<VoteAccount>,
+    membership: &MembershipAccount,
+    voter: Pubkey,
+    option_indices: Vec<u8>,
+    ranked: bool,
+    weight: Option<u64>,
+) -> Result<()> {
     let clock = Clock::get()?;
     
-    // Check poll has not ended
+    // Check voting is open to this member
+    poll.require_can_vote(membership, clock.unix_timestamp)?;
+    
+    // Ranked ballots and ranked-choice polls go together
     require!(
-        clock.unix_timestamp < poll.end_time,
-        SocialError::PollEnded
+        ranked == (poll.mode == PollMode::RankedChoice),
+        SocialError::PollModeMismatch
     );
     
-    // Validate option index
+    // Secret ballots are cast with commit_vote
+    require!(poll.reveal_end_time.is_none(), SocialError::PollModeMismatch);
+    
+    // Token-weighted polls only accept weighted votes, and vice versa
     require!(
-        (option_index as usize) < poll.option_profiles.len(),
-        SocialError::InvalidPollOption
+        weight.is_some() == poll.weight_mint.is_some(),
+        SocialError::PollModeMismatch
     );
     
+    // Validate option indices against the poll mode
+    let selections = poll.selection_mask(&option_indices)?;
+    let option_index = option_indices[0];
+    
     // Record the vote
     vote.poll = poll.key();
-    vote.voter = ctx.accounts.voter.key();
+    vote.voter = voter;
     vote.option_index = option_index;
     vote.voted_at = clock.unix_timestamp;
+    vote.selections = selections;
+    vote.tallied_round = 0;
+    vote.weight = weight.unwrap_or(1);
+    if ranked {
+        vote.ranking = option_indices;
+    }
     
-    // Increment vote count for the selected option
-    let current_votes = poll.votes_per_option[option_index as usize];
-    poll.votes_per_option[option_index as usize] = current_votes
+    // Increment vote count for every selected option, or the first
+    // preference of a ranked ballot
+    poll.add_votes(vote.counted_options(), vote.weight)?;
+    poll.total_voters = poll.total_voters
         .checked_add(1)
         .ok_or(SocialError::ArithmeticOverflow)?;
     
     emit!(PollVoted {
         poll: poll.key(),
-        voter: ctx.accounts.voter.key(),
+        voter,
         option_index,
+        selections,
+        weight: vote.weight,
         timestamp: clock.unix_timestamp,
     });
     
@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use crate::{constants::*, errors::*, instructions::record_vote, state::*};
+
+/// Vote on a token-weighted poll
+/// 
+/// Locks `amount` of the poll's weight mint in an escrow token account owned
+/// by the vote record, and counts the vote with that weight. Locking stops
+/// the same tokens from voting twice; `withdraw_vote_tokens` returns them
+/// once the poll ends or the vote is revoked.
+/// 
+/// Validation:
+/// - Poll must be token-weighted with the given mint
+/// - Voter must be a member of the community, for at least the poll's
+///   minimum membership age
+/// - Poll must have started and not ended
+/// - Voter can only vote once per poll
+/// - Amount must be greater than zero
+/// - Option indices must be valid for the poll mode
+/// 
+/// Events: PollVoted
+#[derive(Accounts)]
+pub struct VotePollWeighted<'info> {
+    #[account(mut)]
+    pub poll: Account<'info, PollAccount>,
+    
+    #[account(
+        seeds = [MEMBERSHIP_SEED, poll.community.as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub membership: Account<'info, MembershipAccount>,
+    
+    #[account(
+        init,
+        payer = voter,
+        space = VOTE_SIZE,
+        seeds = [VOTE_SEED, poll.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub vote: Account<'info, VoteAccount>,
+    
+    #[account(
+        constraint = poll.weight_mint == Some(weight_mint.key()) @ SocialError::PollModeMismatch
+    )]
+    pub weight_mint: Account<'info, Mint>,
+    
+    #[account(
+        mut,
+        token::mint = weight_mint,
+        token::authority = voter
+    )]
+    pub voter_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        init,
+        payer = voter,
+        seeds = [VOTE_ESCROW_SEED, poll.key().as_ref(), voter.key().as_ref()],
+        bump,
+        token::mint = weight_mint,
+        token::authority = vote
+    )]
+    pub vote_escrow: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn vote_poll_weighted(
+    ctx: Context<VotePollWeighted>,
+    option_indices: Vec<u8>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, SocialError::InvalidVoteWeight);
+    
+    // Lock the voting tokens in escrow
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.voter_token_account.to_account_info(),
+                to: ctx.accounts.vote_escrow.to_account_info(),
+                authority: ctx.accounts.voter.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    let accounts = ctx.accounts;
+    record_vote(
+        &mut accounts.poll,
+        &mut accounts.vote,
+        &accounts.membership,
+        accounts.voter.key(),
+        option_indices,
+        false,
+        Some(amount),
+    )
+}
@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Token, TokenAccount, Transfer};
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// Withdraw tokens escrowed by a weighted vote
+/// 
+/// Returns the escrowed balance to the voter and closes the escrow, refunding
+/// its rent. The vote record is the escrow authority and signs by its seeds,
+/// so this works whether or not the record still exists. The poll is only
+/// read if it still exists, so tokens can be withdrawn after it is closed.
+/// 
+/// Validation:
+/// - Signer must be the voter
+/// - Poll must have ended or been closed, or the vote must have been revoked
+/// 
+/// Events: VoteTokensWithdrawn
+#[derive(Accounts)]
+pub struct WithdrawVoteTokens<'info> {
+    /// CHECK: Poll the escrow was created for; may already be closed. The
+    /// escrow's seeds tie it to this address
+    pub poll: UncheckedAccount<'info>,
+    
+    /// CHECK: Vote record PDA that owns the escrow; may already be closed
+    #[account(
+        seeds = [VOTE_SEED, poll.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub vote: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [VOTE_ESCROW_SEED, poll.key().as_ref(), voter.key().as_ref()],
+        bump,
+        token::authority = vote
+    )]
+    pub vote_escrow: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        token::mint = vote_escrow.mint,
+        token::authority = voter
+    )]
+    pub voter_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn withdraw_vote_tokens(ctx: Context<WithdrawVoteTokens>) -> Result<()> {
+    let poll_key = ctx.accounts.poll.key();
+    let voter_key = ctx.accounts.voter.key();
+    let clock = Clock::get()?;
+    
+    // A closed poll has ended; an open one is checked against its end time
+    let poll_ended = ctx.accounts.poll.data_is_empty() || {
+        require_keys_eq!(*ctx.accounts.poll.owner, *ctx.program_id, ErrorCode::AccountOwnedByWrongProgram);
+        let poll = PollAccount::try_deserialize(&mut &ctx.accounts.poll.data.borrow()[..])?;
+        clock.unix_timestamp >= poll.end_time
+    };
+    require!(
+        poll_ended || ctx.accounts.vote.data_is_empty(),
+        SocialError::VoteTokensLocked
+    );
+    
+    let amount = ctx.accounts.vote_escrow.amount;
+    let bump = [ctx.bumps.vote];
+    let signer_seeds: &[&[&[u8]]] = &[&[VOTE_SEED, poll_key.as_ref(), voter_key.as_ref(), &bump]];
+    
+    // Return the escrowed tokens
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vote_escrow.to_account_info(),
+                to: ctx.accounts.voter_token_account.to_account_info(),
+                authority: ctx.accounts.vote.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )?;
+    
+    // Close the escrow and refund its rent
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.vote_escrow.to_account_info(),
+            destination: ctx.accounts.voter.to_account_info(),
+            authority: ctx.accounts.vote.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+    
+    emit!(VoteTokensWithdrawn {
+        poll: poll_key,
+        voter: voter_key,
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
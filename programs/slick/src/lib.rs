@@ -5,8 +5,10 @@ pub mod errors;
 pub mod events;
 pub mod instructions;
 pub mod state;
+pub mod utils;
 
 use instructions::*;
+use state::*;
 declare_id!("54KY3Gg1zcRzvHH64tfoBM3T1mDaahaXUEWv9GCkGoye");
 
 #[program]
@@ -30,6 +32,10 @@ pub mod social_program {
         instructions::update_profile(ctx, display_name, avatar_uri)
     }
 
+    pub fn set_profile_privacy(ctx: Context<SetProfilePrivacy>, requires_approval: bool) -> Result<()> {
+        instructions::set_profile_privacy(ctx, requires_approval)
+    }
+
     pub fn follow_user(ctx: Context<FollowUser>) -> Result<()> {
         instructions::follow_user(ctx)
     }
@@ -38,6 +44,34 @@ pub mod social_program {
         instructions::unfollow_user(ctx)
     }
 
+    pub fn request_follow(ctx: Context<RequestFollow>) -> Result<()> {
+        instructions::request_follow(ctx)
+    }
+
+    pub fn approve_follow(ctx: Context<ApproveFollow>) -> Result<()> {
+        instructions::approve_follow(ctx)
+    }
+
+    pub fn reject_follow(ctx: Context<RejectFollow>) -> Result<()> {
+        instructions::reject_follow(ctx)
+    }
+
+    pub fn block_user(ctx: Context<BlockUser>) -> Result<()> {
+        instructions::block_user(ctx)
+    }
+
+    pub fn unblock_user(ctx: Context<UnblockUser>) -> Result<()> {
+        instructions::unblock_user(ctx)
+    }
+
+    pub fn pin_profile_post(ctx: Context<PinProfilePost>) -> Result<()> {
+        instructions::pin_profile_post(ctx)
+    }
+
+    pub fn unpin_profile_post(ctx: Context<UnpinProfilePost>) -> Result<()> {
+        instructions::unpin_profile_post(ctx)
+    }
+
     // ============= COMMUNITY INSTRUCTIONS =============
     pub fn create_community(
         ctx: Context<CreateCommunity>,
@@ -56,6 +90,14 @@ pub mod social_program {
         instructions::leave_community(ctx)
     }
 
+    pub fn add_moderator(ctx: Context<AddModerator>) -> Result<()> {
+        instructions::add_moderator(ctx)
+    }
+
+    pub fn remove_moderator(ctx: Context<RemoveModerator>) -> Result<()> {
+        instructions::remove_moderator(ctx)
+    }
+
     // ============= POST INSTRUCTIONS =============
     pub fn create_post(
         ctx: Context<CreatePost>,
@@ -67,6 +109,14 @@ pub mod social_program {
         instructions::create_post(ctx, content_uri, content_hash, is_anonymous, pseudonym)
     }
 
+    pub fn create_profile_post(
+        ctx: Context<CreateProfilePost>,
+        content_uri: String,
+        content_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::create_profile_post(ctx, content_uri, content_hash)
+    }
+
     pub fn like_post(ctx: Context<LikePost>) -> Result<()> {
         instructions::like_post(ctx)
     }
@@ -83,21 +133,259 @@ pub mod social_program {
         instructions::comment_on_post(ctx, content_uri, content_hash)
     }
 
-    pub fn tip_post(ctx: Context<TipPost>, amount: u64) -> Result<()> {
-        instructions::tip_post(ctx, amount)
+    pub fn tip_post(
+        ctx: Context<TipPost>,
+        amount: u64,
+        memo: Option<String>,
+    ) -> Result<()> {
+        instructions::tip_post(ctx, amount, memo)
+    }
+
+    pub fn tip_comment(
+        ctx: Context<TipComment>,
+        amount: u64,
+        memo: Option<String>,
+    ) -> Result<()> {
+        instructions::tip_comment(ctx, amount, memo)
+    }
+
+    pub fn tip_profile(
+        ctx: Context<TipProfile>,
+        amount: u64,
+        memo: Option<String>,
+    ) -> Result<()> {
+        instructions::tip_profile(ctx, amount, memo)
+    }
+
+    pub fn pin_post(ctx: Context<PinPost>) -> Result<()> {
+        instructions::pin_post(ctx)
+    }
+
+    pub fn unpin_post(ctx: Context<UnpinPost>) -> Result<()> {
+        instructions::unpin_post(ctx)
+    }
+
+    pub fn lock_post(ctx: Context<LockPost>, locked_until: Option<i64>) -> Result<()> {
+        instructions::lock_post(ctx, locked_until)
+    }
+
+    pub fn unlock_post(ctx: Context<UnlockPost>) -> Result<()> {
+        instructions::unlock_post(ctx)
+    }
+
+    pub fn repost(
+        ctx: Context<Repost>,
+        quote_uri: Option<String>,
+        quote_hash: Option<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::repost(ctx, quote_uri, quote_hash)
+    }
+
+    pub fn create_bounty(ctx: Context<CreateBounty>, amount: u64, expires_at: i64) -> Result<()> {
+        instructions::create_bounty(ctx, amount, expires_at)
+    }
+
+    pub fn award_bounty(ctx: Context<AwardBounty>) -> Result<()> {
+        instructions::award_bounty(ctx)
+    }
+
+    pub fn refund_bounty(ctx: Context<RefundBounty>) -> Result<()> {
+        instructions::refund_bounty(ctx)
+    }
+
+    pub fn set_post_price(ctx: Context<SetPostPrice>, price_lamports: u64) -> Result<()> {
+        instructions::set_post_price(ctx, price_lamports)
+    }
+
+    pub fn purchase_post(ctx: Context<PurchasePost>, price_lamports: u64) -> Result<()> {
+        instructions::purchase_post(ctx, price_lamports)
+    }
+
+    pub fn set_post_access(ctx: Context<SetPostAccess>, subscribers_only: bool) -> Result<()> {
+        instructions::set_post_access(ctx, subscribers_only)
+    }
+
+    pub fn mint_post_nft(
+        ctx: Context<MintPostNft>,
+        name: String,
+        symbol: String,
+        seller_fee_basis_points: u16,
+    ) -> Result<()> {
+        instructions::mint_post_nft(ctx, name, symbol, seller_fee_basis_points)
     }
 
     // ============= POLL INSTRUCTIONS =============
     pub fn create_poll(
         ctx: Context<CreatePoll>,
         question_uri: String,
+        option_kind: PollOptionKind,
         option_profiles: Vec<Pubkey>,
+        option_labels: Vec<String>,
+        config: PollConfig,
         end_time: i64,
     ) -> Result<()> {
-        instructions::create_poll(ctx, question_uri, option_profiles, end_time)
+        instructions::create_poll(
+            ctx,
+            question_uri,
+            option_kind,
+            option_profiles,
+            option_labels,
+            config,
+            end_time,
+        )
     }
 
     pub fn vote_poll(ctx: Context<VotePoll>, option_index: u8) -> Result<()> {
         instructions::vote_poll(ctx, option_index)
     }
+
+    pub fn vote_poll_multi(ctx: Context<VotePoll>, option_indices: Vec<u8>) -> Result<()> {
+        instructions::vote_poll_multi(ctx, option_indices)
+    }
+
+    pub fn vote_poll_ranked(ctx: Context<VotePoll>, ranking: Vec<u8>) -> Result<()> {
+        instructions::vote_poll_ranked(ctx, ranking)
+    }
+
+    pub fn vote_poll_weighted(
+        ctx: Context<VotePollWeighted>,
+        option_indices: Vec<u8>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::vote_poll_weighted(ctx, option_indices, amount)
+    }
+
+    pub fn withdraw_vote_tokens(ctx: Context<WithdrawVoteTokens>) -> Result<()> {
+        instructions::withdraw_vote_tokens(ctx)
+    }
+
+    pub fn commit_vote(ctx: Context<VotePoll>, commitment: [u8; 32]) -> Result<()> {
+        instructions::commit_vote(ctx, commitment)
+    }
+
+    pub fn reveal_vote(
+        ctx: Context<RevealVote>,
+        option_indices: Vec<u8>,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        instructions::reveal_vote(ctx, option_indices, salt)
+    }
+
+    pub fn change_vote(ctx: Context<ChangeVote>, option_indices: Vec<u8>) -> Result<()> {
+        instructions::change_vote(ctx, option_indices)
+    }
+
+    pub fn revoke_vote(ctx: Context<RevokeVote>) -> Result<()> {
+        instructions::revoke_vote(ctx)
+    }
+
+    pub fn tally_poll(ctx: Context<TallyPoll>) -> Result<()> {
+        instructions::tally_poll(ctx)
+    }
+
+    pub fn finalize_poll(ctx: Context<FinalizePoll>) -> Result<()> {
+        instructions::finalize_poll(ctx)
+    }
+
+    pub fn close_vote_record(ctx: Context<CloseVoteRecord>) -> Result<()> {
+        instructions::close_vote_record(ctx)
+    }
+
+    pub fn close_poll(ctx: Context<ClosePoll>) -> Result<()> {
+        instructions::close_poll(ctx)
+    }
+
+    pub fn migrate_poll(ctx: Context<MigratePoll>) -> Result<()> {
+        instructions::migrate_poll(ctx)
+    }
+
+    pub fn migrate_vote(ctx: Context<MigrateVote>) -> Result<()> {
+        instructions::migrate_vote(ctx)
+    }
+
+    pub fn seat_elected_moderator(ctx: Context<SeatElectedModerator>) -> Result<()> {
+        instructions::seat_elected_moderator(ctx)
+    }
+
+    pub fn fund_poll_prize(ctx: Context<FundPollPrize>, amount: u64) -> Result<()> {
+        instructions::fund_poll_prize(ctx, amount)
+    }
+
+    pub fn fund_poll_prize_token(ctx: Context<FundPollPrizeToken>, amount: u64) -> Result<()> {
+        instructions::fund_poll_prize_token(ctx, amount)
+    }
+
+    pub fn settle_prize_poll<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SettlePrizePoll<'info>>,
+    ) -> Result<()> {
+        instructions::settle_prize_poll(ctx)
+    }
+
+    // ============= MODERATION INSTRUCTIONS =============
+    pub fn report_post(
+        ctx: Context<ReportPost>,
+        reason: ReportReason,
+        evidence_uri: String,
+    ) -> Result<()> {
+        instructions::report_post(ctx, reason, evidence_uri)
+    }
+
+    pub fn report_comment(
+        ctx: Context<ReportComment>,
+        reason: ReportReason,
+        evidence_uri: String,
+    ) -> Result<()> {
+        instructions::report_comment(ctx, reason, evidence_uri)
+    }
+
+    pub fn resolve_report(ctx: Context<ResolveReport>, outcome: ReportOutcome) -> Result<()> {
+        instructions::resolve_report(ctx, outcome)
+    }
+
+    pub fn set_report_threshold(ctx: Context<SetReportThreshold>, threshold: u32) -> Result<()> {
+        instructions::set_report_threshold(ctx, threshold)
+    }
+
+    // ============= GOVERNANCE INSTRUCTIONS =============
+    pub fn set_governance_config(
+        ctx: Context<SetGovernanceConfig>,
+        min_quorum: u32,
+        min_threshold_bps: u16,
+        min_timelock: i64,
+    ) -> Result<()> {
+        instructions::set_governance_config(ctx, min_quorum, min_threshold_bps, min_timelock)
+    }
+
+    pub fn create_proposal(
+        ctx: Context<CreateProposal>,
+        action: ProposalAction,
+        threshold_bps: u16,
+        timelock: i64,
+    ) -> Result<()> {
+        instructions::create_proposal(ctx, action, threshold_bps, timelock)
+    }
+
+    pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+        instructions::execute_proposal(ctx)
+    }
+
+    pub fn close_proposal(ctx: Context<CloseProposal>) -> Result<()> {
+        instructions::close_proposal(ctx)
+    }
+
+    // ============= SUBSCRIPTION INSTRUCTIONS =============
+    pub fn set_subscription_tiers(
+        ctx: Context<SetSubscriptionTiers>,
+        tiers: Vec<SubscriptionTier>,
+    ) -> Result<()> {
+        instructions::set_subscription_tiers(ctx, tiers)
+    }
+
+    pub fn subscribe(ctx: Context<Subscribe>, tier: u8, price_lamports: u64) -> Result<()> {
+        instructions::subscribe(ctx, tier, price_lamports)
+    }
+
+    pub fn cancel_subscription(ctx: Context<CancelSubscription>) -> Result<()> {
+        instructions::cancel_subscription(ctx)
+    }
 }
\ No newline at end of file
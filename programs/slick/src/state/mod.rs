@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::errors::SocialError;
 
 /// User profile account
 /// PDA: ["profile", user_wallet]
@@ -25,6 +26,31 @@ pub struct ProfileAccount {
     
     /// Timestamp when profile was created
     pub created_at: i64, // 8
+    
+    /// Counter for generating profile post IDs
+    pub post_counter: u64, // 8
+    
+    /// Posts pinned to the top of the profile
+    pub pinned_posts: Vec<Pubkey>, // 4 + (32 * count, max 3)
+    
+    /// Whether new followers must be approved by the owner
+    pub requires_approval: bool, // 1
+    
+    /// Subscription tiers offered by the owner (max 3)
+    pub subscription_tiers: Vec<SubscriptionTier>, // 4 + (16 * 3)
+    
+    /// Lifetime tips received across posts, comments and the profile
+    pub total_tips_received: u64, // 8
+}
+
+/// A subscription tier offered by a profile
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct SubscriptionTier {
+    /// Price in lamports per period
+    pub price_lamports: u64, // 8
+    
+    /// Seconds of access each payment buys
+    pub period: i64, // 8
 }
 
 /// Community account
@@ -58,6 +84,78 @@ pub struct CommunityAccount {
     
     /// Timestamp when community was created
     pub created_at: i64, // 8
+    
+    /// Posts pinned to the top of the community by moderators
+    pub pinned_posts: Vec<Pubkey>, // 4 + (32 * count, max 5)
+    
+    /// Number of reports that auto-hides a post or comment (0 = disabled)
+    pub report_threshold: u32, // 4
+    
+    /// Who may join the community
+    pub join_policy: JoinPolicy, // 1
+    
+    /// Lamports paid to the creator to join (0 = free)
+    pub join_fee: u64, // 8
+    
+    /// Minimum voter quorum for proposal polls (0 = governance disabled)
+    pub min_governance_quorum: u32, // 4
+    
+    /// Minimum approval threshold for proposals, in basis points
+    pub min_governance_threshold_bps: u16, // 2
+    
+    /// Minimum seconds between a proposal passing and its execution
+    pub min_governance_timelock: i64, // 8
+}
+
+/// Who may join a community
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum JoinPolicy {
+    /// Anyone with a profile may join (paying the join fee, if any)
+    Open,
+    /// Nobody new may join
+    Closed,
+    /// Only users with an active subscription to the creator may join
+    Subscribers,
+}
+
+impl CommunityAccount {
+    /// The creator is always a moderator; anyone else needs a moderator
+    /// record whose term has not ended
+    pub fn is_moderator(
+        &self,
+        authority: &Pubkey,
+        moderator: Option<&ModeratorAccount>,
+        now: i64,
+    ) -> bool {
+        *authority == self.creator
+            || moderator.is_some_and(|m| {
+                m.user == *authority && m.term_ends_at.is_none_or(|ends_at| now < ends_at)
+            })
+    }
+}
+
+/// Moderator record
+/// PDA: ["moderator", community_pubkey, user_wallet]
+/// 
+/// Grants a community member moderation rights (pinning, etc.).
+/// Added and removed by the community creator, by governance proposal, or
+/// seated by winning a moderator election for a fixed term.
+#[account]
+pub struct ModeratorAccount {
+    /// Community this moderator belongs to
+    pub community: Pubkey, // 32
+    
+    /// User who is a moderator
+    pub user: Pubkey, // 32
+    
+    /// Wallet (or proposal / election poll) that granted moderator rights
+    pub added_by: Pubkey, // 32
+    
+    /// Timestamp when moderator was added
+    pub added_at: i64, // 8
+    
+    /// When an elected moderator's term ends (None = no term limit)
+    pub term_ends_at: Option<i64>, // 1 + 8
 }
 
 /// Membership record
@@ -77,18 +175,29 @@ pub struct MembershipAccount {
     pub joined_at: i64, // 8
 }
 
+/// Where a post was published
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum PostScope {
+    /// Posted in a community, interactions require membership
+    Community,
+    /// Posted on the author's profile timeline for their followers
+    Profile,
+}
+
 /// Post account
 /// PDA: ["post", community_pubkey, post_id (u64)]
+/// PDA: ["post", profile_pubkey, post_id (u64)] for profile-scoped posts
 /// 
-/// Represents a post in a community. Content is off-chain (URI + hash).
+/// Represents a post in a community or on a profile timeline.
+/// Content is off-chain (URI + hash).
 /// Author can be None for anonymous posts (ghost mode).
 /// Counters track engagement metrics on-chain.
 #[account]
 pub struct PostAccount {
-    /// Community where post was created
+    /// Community where post was created (author profile if profile-scoped)
     pub community: Pubkey, // 32
     
-    /// Sequential ID within the community
+    /// Sequential ID within the community or profile
     pub post_id: u64, // 8
     
     /// URI to post content (IPFS/Arweave)
@@ -114,6 +223,180 @@ pub struct PostAccount {
     
     /// Timestamp when post was created
     pub created_at: i64, // 8
+    
+    /// Number of times this post was reposted
+    pub reposts_count: u64, // 8
+    
+    /// Whether the post lives in a community or on a profile
+    pub scope: PostScope, // 1
+    
+    /// Whether comments, likes and tips are disabled
+    pub locked: bool, // 1
+    
+    /// Timestamp when the lock expires (None = locked until unlocked)
+    pub locked_until: Option<i64>, // 1 + 8
+    
+    /// Number of abuse reports filed against this post
+    pub reports_count: u32, // 4
+    
+    /// Hidden by moderators or by crossing the report threshold
+    pub hidden: bool, // 1
+    
+    /// Lamports escrowed in the post's open bounty (0 = no bounty)
+    pub bounty_lamports: u64, // 8
+    
+    /// Price in lamports to unlock the post's content (0 = free)
+    pub price_lamports: u64, // 8
+    
+    /// Number of paid unlocks sold
+    pub unlocks_count: u64, // 8
+    
+    /// Whether only the author's active subscribers may like and comment
+    pub subscribers_only: bool, // 1
+    
+    /// Mint of the post's NFT (None = not minted)
+    pub nft_mint: Option<Pubkey>, // 1 + 32
+    
+    /// Hidden by a moderator's Remove or BanAuthor decision
+    pub removed: bool, // 1
+}
+
+impl PostAccount {
+    /// Whether the post is currently locked, honoring lock expiry
+    pub fn is_locked(&self, now: i64) -> bool {
+        self.locked && self.locked_until.is_none_or(|until| now < until)
+    }
+}
+
+/// Bounty on a post
+/// PDA: ["bounty", post_pubkey]
+/// 
+/// Escrows lamports in this account until the post author awards them to a
+/// commenter, or refunds them to the author once the bounty expires. Closed
+/// either way.
+#[account]
+pub struct BountyAccount {
+    /// Post the bounty is attached to
+    pub post: Pubkey, // 32
+    
+    /// Post author who funded the bounty
+    pub author: Pubkey, // 32
+    
+    /// Bounty amount in lamports
+    pub amount: u64, // 8
+    
+    /// Timestamp after which the bounty can no longer be awarded
+    pub expires_at: i64, // 8
+    
+    /// Timestamp when the bounty was created
+    pub created_at: i64, // 8
+}
+
+/// Paid post unlock receipt
+/// PDA: ["unlock", post_pubkey, buyer_wallet]
+/// 
+/// Proves a buyer paid for a premium post. Off-chain gateways check for this
+/// receipt before serving the post's decrypted content.
+#[account]
+pub struct UnlockAccount {
+    /// Post that was unlocked
+    pub post: Pubkey, // 32
+    
+    /// Wallet that paid for access
+    pub buyer: Pubkey, // 32
+    
+    /// Lamports paid to the author
+    pub price_paid: u64, // 8
+    
+    /// Timestamp of the purchase
+    pub unlocked_at: i64, // 8
+}
+
+/// Subscription to a creator
+/// PDA: ["subscription", creator_wallet, subscriber_wallet]
+/// 
+/// Each payment extends `expires_at` by the tier's period. Gated posts and
+/// subscriber-only communities check for an unexpired subscription; off-chain
+/// gateways can do the same.
+#[account]
+pub struct SubscriptionAccount {
+    /// Creator being subscribed to
+    pub creator: Pubkey, // 32
+    
+    /// Subscriber wallet
+    pub subscriber: Pubkey, // 32
+    
+    /// Index of the tier last paid for
+    pub tier: u8, // 1
+    
+    /// Timestamp of the first payment
+    pub started_at: i64, // 8
+    
+    /// Timestamp when access ends
+    pub expires_at: i64, // 8
+    
+    /// Total lamports paid to the creator
+    pub total_paid: u64, // 8
+}
+
+impl SubscriptionAccount {
+    /// Whether the subscription has not yet expired
+    pub fn is_active(&self, now: i64) -> bool {
+        now < self.expires_at
+    }
+}
+
+/// Running total of one user's tips to a post
+/// PDA: ["tip_record", post_pubkey, tipper_wallet]
+/// 
+/// Lets clients rank a post's top supporters without indexing every
+/// PostTipped event.
+#[account]
+pub struct TipRecordAccount {
+    /// Post that was tipped
+    pub post: Pubkey, // 32
+    
+    /// User who tipped
+    pub tipper: Pubkey, // 32
+    
+    /// Total lamports tipped to the post by this user
+    pub total_lamports: u64, // 8
+    
+    /// Number of tips sent
+    pub tips_count: u32, // 4
+    
+    /// Timestamp of the first tip
+    pub first_tipped_at: i64, // 8
+    
+    /// Timestamp of the latest tip
+    pub last_tipped_at: i64, // 8
+}
+
+/// Repost record
+/// PDA: ["repost", original_post_pubkey, user_wallet]
+/// 
+/// Records that a user shared a post, either to their followers or into a
+/// community they belong to. A quote repost carries its own content URI + hash.
+/// Enforces one-repost-per-user-per-post.
+#[account]
+pub struct RepostAccount {
+    /// Post being reposted
+    pub original_post: Pubkey, // 32
+    
+    /// User who reposted
+    pub reposter: Pubkey, // 32
+    
+    /// Destination community (None if shared to followers)
+    pub community: Option<Pubkey>, // 1 + 32
+    
+    /// URI to quote content (None for a plain repost)
+    pub quote_uri: Option<String>, // 1 + 4 + max 200
+    
+    /// Hash of quote content for integrity verification
+    pub quote_hash: Option<[u8; 32]>, // 1 + 32
+    
+    /// Timestamp when reposted
+    pub reposted_at: i64, // 8
 }
 
 /// Like record
@@ -157,6 +440,18 @@ pub struct CommentAccount {
     
     /// Timestamp when comment was created
     pub created_at: i64, // 8
+    
+    /// Number of abuse reports filed against this comment
+    pub reports_count: u32, // 4
+    
+    /// Hidden by moderators or by crossing the report threshold
+    pub hidden: bool, // 1
+    
+    /// Total tips received in lamports
+    pub total_tip_lamports: u64, // 8
+    
+    /// Hidden by a moderator's Remove or BanAuthor decision
+    pub removed: bool, // 1
 }
 
 /// Follow relationship
@@ -176,10 +471,103 @@ pub struct FollowAccount {
     pub followed_at: i64, // 8
 }
 
+/// Pending follow request
+/// PDA: ["follow_request", follower_profile, followed_profile]
+/// 
+/// Created when following a profile that requires approval. Approving the
+/// request converts it into a FollowAccount; rejecting it closes it.
+#[account]
+pub struct FollowRequestAccount {
+    /// Profile requesting to follow
+    pub follower: Pubkey, // 32
+    
+    /// Profile being requested
+    pub followed: Pubkey, // 32
+    
+    /// Timestamp when request was made
+    pub requested_at: i64, // 8
+}
+
+/// Block relationship
+/// PDA: ["block", blocker_profile, blocked_profile]
+/// 
+/// Records that one user blocked another. While it exists the blocked user
+/// cannot follow the blocker or like, comment on, or tip the blocker's posts.
+#[account]
+pub struct BlockAccount {
+    /// Profile that created the block
+    pub blocker: Pubkey, // 32
+    
+    /// Profile being blocked
+    pub blocked: Pubkey, // 32
+    
+    /// Timestamp when block occurred
+    pub blocked_at: i64, // 8
+}
+
+/// What the options of a poll refer to
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum PollOptionKind {
+    /// Options are user profiles (option_profiles)
+    Profile,
+    /// Options are short text answers (option_labels)
+    Text,
+    /// Options are URIs to off-chain content (option_labels)
+    Uri,
+}
+
+/// How voters select options in a poll
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum PollMode {
+    /// Exactly one option per voter
+    SingleChoice,
+    /// Up to `max_selections` options per voter
+    MultiChoice,
+    /// Any number of options per voter
+    Approval,
+    /// Voters rank options; the winner is found by instant-runoff
+    RankedChoice,
+}
+
+/// Voting rules chosen when a poll is created
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct PollConfig {
+    /// How voters select options
+    pub mode: PollMode,
+    
+    /// Maximum options per voter (multi-choice polls only)
+    pub max_selections: u8,
+    
+    /// Whether voters may change or revoke their vote before the poll ends
+    pub allow_vote_change: bool,
+    
+    /// SPL mint whose escrowed balance weights each vote (None = one member, one vote)
+    pub weight_mint: Option<Pubkey>,
+    
+    /// End of the reveal window for secret ballots (None = open ballot)
+    pub reveal_end_time: Option<i64>,
+    
+    /// When voting opens (None = immediately)
+    pub start_time: Option<i64>,
+    
+    /// Minimum number of voters for the result to stand (0 = no quorum)
+    pub quorum: u32,
+    
+    /// Seconds a voter must have been a member before voting (0 = no minimum)
+    pub min_membership_age: i64,
+    
+    /// Moderator seats filled by the winners (0 = not an election)
+    pub election_seats: u8,
+    
+    /// Seconds an elected moderator serves, counted from the end of voting
+    pub term_length: i64,
+}
+
 /// Poll account
 /// PDA: ["poll", community_pubkey, poll_id (u64)]
 /// 
-/// Represents a poll in a community. Options are user profiles (Pubkeys).
+/// Represents a poll in a community. Options are either user profiles
+/// (Pubkeys) or on-chain labels (text answers or URIs).
 /// Vote counts are maintained on-chain for transparency.
 /// Question details are off-chain (URI).
 #[account]
@@ -193,10 +581,12 @@ pub struct PollAccount {
     /// URI to poll question/description
     pub question_uri: String, // 4 + max 200
     
-    /// Profile pubkeys that are options in this poll
+    /// Profile pubkeys that are options in this poll (profile polls only)
     pub option_profiles: Vec<Pubkey>, // 4 + (32 * count, max 10)
     
-    /// Vote count for each option (parallel to option_profiles)
+    /// Ballots cast for each option (parallel to the poll's options). Kept as
+    /// u32 so existing polls keep their layout; token weights can exceed it,
+    /// so they are summed separately in `weighted_votes` (see `tally`)
     pub votes_per_option: Vec<u32>, // 4 + (4 * count, max 10)
     
     /// Creator of the poll
@@ -207,13 +597,208 @@ pub struct PollAccount {
     
     /// Timestamp when poll was created
     pub created_at: i64, // 8
+    
+    /// What the options refer to
+    pub option_kind: PollOptionKind, // 1
+    
+    /// Text answers or URIs that are options in this poll (label polls only)
+    pub option_labels: Vec<String>, // 4 + ((4 + max 100) * count, max 10)
+    
+    /// How voters select options
+    pub mode: PollMode, // 1
+    
+    /// Maximum options per voter
+    pub max_selections: u8, // 1
+    
+    /// Number of voters, as opposed to the sum of votes_per_option
+    pub total_voters: u32, // 4
+    
+    /// Whether voters may change or revoke their vote before the poll ends
+    pub allow_vote_change: bool, // 1
+    
+    /// Timestamp when results were finalized
+    pub finalized_at: Option<i64>, // 1 + 8
+    
+    /// Bitmask of winning options, set at finalization (ties share the win)
+    pub winners: u16, // 2
+    
+    /// Vote records not yet closed, counted from finalization
+    pub open_vote_records: u32, // 4
+    
+    /// SPL mint whose escrowed balance weights each vote (None = one member, one vote)
+    pub weight_mint: Option<Pubkey>, // 1 + 32
+    
+    /// End of the reveal window for secret ballots (None = open ballot)
+    pub reveal_end_time: Option<i64>, // 1 + 8
+    
+    /// Committed secret ballots not yet revealed
+    pub unrevealed_votes: u32, // 4
+    
+    /// Unix timestamp when voting opens
+    pub start_time: i64, // 8
+    
+    /// Minimum number of voters for the result to stand (0 = no quorum)
+    pub quorum: u32, // 4
+    
+    /// Seconds a voter must have been a member before voting
+    pub min_membership_age: i64, // 8
+    
+    /// Whether quorum was reached, evaluated at finalization
+    pub quorum_met: bool, // 1
+    
+    /// Moderator seats filled by the winners (0 = not an election)
+    pub election_seats: u8, // 1
+    
+    /// Seconds an elected moderator serves, counted from the end of voting
+    pub term_length: i64, // 8
+    
+    /// Whether a prize is escrowed and not yet settled
+    pub has_prize: bool, // 1
+    
+    /// Bitmask of winning options already seated as moderators
+    pub seated_winners: u16, // 2
+    
+    /// Escrowed token weight for each option on token-weighted polls
+    /// (empty otherwise)
+    pub weighted_votes: Vec<u64>, // 4 + (8 * count, max 10)
+}
+
+impl PollAccount {
+    /// When results can no longer change: the end of the reveal window for
+    /// secret ballots, otherwise end_time
+    pub fn results_at(&self) -> i64 {
+        self.reveal_end_time.unwrap_or(self.end_time)
+    }
+    
+    /// Checks that voting is open and the member has belonged to the
+    /// community long enough to vote
+    pub fn require_can_vote(&self, membership: &MembershipAccount, now: i64) -> Result<()> {
+        require!(now >= self.start_time, SocialError::PollNotStarted);
+        require!(now < self.end_time, SocialError::PollEnded);
+        
+        let member_for = now
+            .checked_sub(membership.joined_at)
+            .ok_or(SocialError::ArithmeticOverflow)?;
+        require!(
+            member_for >= self.min_membership_age,
+            SocialError::MembershipTooNew
+        );
+        Ok(())
+    }
+    
+    /// Whether results have been finalized
+    pub fn is_finalized(&self) -> bool {
+        self.finalized_at.is_some()
+    }
+    
+    /// Votes for an option: escrowed token weight on token-weighted polls,
+    /// otherwise the number of ballots
+    pub fn tally(&self, index: usize) -> u64 {
+        match self.weight_mint {
+            Some(_) => self.weighted_votes[index],
+            None => self.votes_per_option[index] as u64,
+        }
+    }
+    
+    /// Bitmask of the `seats` options with the most votes, ignoring options
+    /// with none (ties at the cutoff go to the lower index)
+    pub fn top_options(&self, seats: usize) -> u16 {
+        let mut ranked: Vec<usize> = (0..self.option_count())
+            .filter(|&index| self.tally(index) > 0)
+            .collect();
+        ranked.sort_by_key(|&index| std::cmp::Reverse(self.tally(index)));
+        ranked
+            .into_iter()
+            .take(seats)
+            .fold(0, |mask, index| mask | (1 << index))
+    }
+    
+    /// Bitmask of the options with the most votes (none if nobody voted)
+    pub fn leading_options(&self) -> u16 {
+        let max = (0..self.option_count()).map(|index| self.tally(index)).max().unwrap_or_default();
+        if max == 0 {
+            return 0;
+        }
+        (0..self.option_count())
+            .filter(|&index| self.tally(index) == max)
+            .fold(0, |mask, index| mask | (1 << index))
+    }
+    
+    /// Number of options, regardless of option kind
+    pub fn option_count(&self) -> usize {
+        self.votes_per_option.len()
+    }
+    
+    /// Maximum number of options a single voter may select
+    pub fn selection_limit(&self) -> usize {
+        match self.mode {
+            PollMode::SingleChoice => 1,
+            PollMode::MultiChoice => self.max_selections as usize,
+            PollMode::Approval | PollMode::RankedChoice => self.option_count(),
+        }
+    }
+    
+    /// Validates a voter's selected option indices and packs them into a bitmask
+    pub fn selection_mask(&self, option_indices: &[u8]) -> Result<u16> {
+        require!(!option_indices.is_empty(), SocialError::InvalidPollOption);
+        require!(
+            option_indices.len() <= self.selection_limit(),
+            SocialError::TooManySelections
+        );
+        
+        let mut mask = 0u16;
+        for &index in option_indices {
+            require!(
+                (index as usize) < self.option_count(),
+                SocialError::InvalidPollOption
+            );
+            let bit = 1u16 << index;
+            require!(mask & bit == 0, SocialError::DuplicatePollOption);
+            mask |= bit;
+        }
+        
+        Ok(mask)
+    }
+    
+    /// Adds one vote, and `weight` on token-weighted polls, to every option
+    /// in the bitmask
+    pub fn add_votes(&mut self, counted: u16, weight: u64) -> Result<()> {
+        for (index, votes) in self.votes_per_option.iter_mut().enumerate() {
+            if counted & (1 << index) != 0 {
+                *votes = votes.checked_add(1).ok_or(SocialError::ArithmeticOverflow)?;
+            }
+        }
+        for (index, votes) in self.weighted_votes.iter_mut().enumerate() {
+            if counted & (1 << index) != 0 {
+                *votes = votes.checked_add(weight).ok_or(SocialError::ArithmeticOverflow)?;
+            }
+        }
+        Ok(())
+    }
+    
+    /// Removes one vote, and `weight` on token-weighted polls, from every
+    /// option in the bitmask
+    pub fn remove_votes(&mut self, counted: u16, weight: u64) -> Result<()> {
+        for (index, votes) in self.votes_per_option.iter_mut().enumerate() {
+            if counted & (1 << index) != 0 {
+                *votes = votes.checked_sub(1).ok_or(SocialError::ArithmeticOverflow)?;
+            }
+        }
+        for (index, votes) in self.weighted_votes.iter_mut().enumerate() {
+            if counted & (1 << index) != 0 {
+                *votes = votes.checked_sub(weight).ok_or(SocialError::ArithmeticOverflow)?;
+            }
+        }
+        Ok(())
+    }
 }
 
 /// Vote record
 /// PDA: ["vote", poll_pubkey, voter_wallet]
 /// 
-/// Records that a user voted in a poll and which option they chose.
-/// Enforces one-vote-per-user-per-poll.
+/// Records that a user voted in a poll and which options they chose.
+/// On secret ballot polls the choice stays hidden behind `commitment` until
+/// the voter reveals it. Enforces one-vote-per-user-per-poll.
 #[account]
 pub struct VoteAccount {
     /// Poll this vote belongs to
@@ -222,9 +807,334 @@ pub struct VoteAccount {
     /// User who voted
     pub voter: Pubkey, // 32
     
-    /// Index of the option voted for
+    /// Index of the first option voted for
     pub option_index: u8, // 1
     
     /// Timestamp when vote was cast
     pub voted_at: i64, // 8
-}
\ No newline at end of file
+    
+    /// Bitmask of every option voted for (bit i = option i)
+    pub selections: u16, // 2
+    
+    /// Options in preference order (ranked-choice polls only)
+    pub ranking: Vec<u8>, // 4 + (1 * count, max 10)
+    
+    /// Number of runoff rounds this ballot has been counted in
+    pub tallied_round: u8, // 1
+    
+    /// Weight this ballot adds to each counted option (escrowed tokens on
+    /// token-weighted polls, otherwise 1)
+    pub weight: u64, // 8
+    
+    /// Hash of the hidden choice while a secret ballot is unrevealed
+    pub commitment: Option<[u8; 32]>, // 1 + 32
+}
+
+impl VoteAccount {
+    /// Options this ballot adds to `votes_per_option`: every selection, or
+    /// only the first preference of a ranked ballot
+    pub fn counted_options(&self) -> u16 {
+        if self.ranking.is_empty() {
+            self.selections
+        } else {
+            1 << self.option_index
+        }
+    }
+    
+    /// Highest-ranked option that has not been eliminated, if any
+    pub fn current_preference(&self, eliminated: u16) -> Option<u8> {
+        self.ranking
+            .iter()
+            .copied()
+            .find(|&option| eliminated & (1 << option) == 0)
+    }
+}
+
+/// Ranked-choice tally
+/// PDA: ["poll_result", poll_pubkey]
+/// 
+/// Running instant-runoff count for a ranked-choice poll. Each round every
+/// ballot is counted toward its highest-ranked remaining option; ballots are
+/// passed to `tally_poll` in batches, so a round can span many transactions.
+#[account]
+pub struct PollResultAccount {
+    /// Poll being tallied
+    pub poll: Pubkey, // 32
+    
+    /// Current runoff round (0-based)
+    pub round: u8, // 1
+    
+    /// Ballots counted so far in the current round
+    pub processed_votes: u32, // 4
+    
+    /// Counts for the current round (parallel to the poll's options)
+    pub current_counts: Vec<u32>, // 4 + (4 * count, max 10)
+    
+    /// Ballots with no remaining preference in the current round
+    pub exhausted: u32, // 4
+    
+    /// Bitmask of eliminated options (bit i = option i)
+    pub eliminated: u16, // 2
+    
+    /// Final counts of every completed round
+    pub rounds: Vec<Vec<u32>>, // 4 + ((4 + 4 * count) * rounds, max 10 each)
+    
+    /// Winning option index, once decided
+    pub winner: Option<u8>, // 1 + 1
+    
+    /// Timestamp when the tally completed
+    pub completed_at: Option<i64>, // 1 + 8
+}
+
+/// Poll prize escrow
+/// PDA: ["prize", poll_pubkey]
+/// 
+/// Holds a prize for the winner of a profile poll: lamports in this account,
+/// or SPL tokens in the vault token account ["prize_vault", poll_pubkey] it
+/// owns. Settlement pays the winning profiles' owners, split evenly on ties,
+/// or refunds the funder if nobody won.
+#[account]
+pub struct PrizeAccount {
+    /// Poll the prize is for
+    pub poll: Pubkey, // 32
+    
+    /// Poll creator who funded the prize, refunded if nobody wins
+    pub funder: Pubkey, // 32
+    
+    /// SPL mint of the prize (None = SOL)
+    pub mint: Option<Pubkey>, // 1 + 32
+    
+    /// Prize amount in lamports or token base units
+    pub amount: u64, // 8
+    
+    /// Timestamp when the prize was funded
+    pub funded_at: i64, // 8
+}
+
+/// Change a governance proposal applies to its community
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum ProposalAction {
+    /// Replace the community description URI
+    UpdateDescription { description_uri: String },
+    /// Grant moderator rights to a member
+    AddModerator { user: Pubkey },
+    /// Revoke moderator rights
+    RemoveModerator { user: Pubkey },
+    /// Change the lamports paid to join
+    SetJoinFee { join_fee: u64 },
+    /// Open or close the community to new members
+    SetJoinPolicy { join_policy: JoinPolicy },
+}
+
+impl ProposalAction {
+    /// User a moderator action applies to (default key for other actions)
+    pub fn target_user(&self) -> Pubkey {
+        match self {
+            ProposalAction::AddModerator { user } | ProposalAction::RemoveModerator { user } => *user,
+            _ => Pubkey::default(),
+        }
+    }
+}
+
+/// Governance proposal
+/// PDA: ["proposal", poll_pubkey]
+/// 
+/// A community action put to a vote. The poll's first option approves the
+/// proposal and its second rejects it. Once the poll is finalized with quorum
+/// and the approving share of votes reaches `threshold_bps`, anyone may
+/// execute the action after `timelock` seconds from the end of voting.
+#[account]
+pub struct ProposalAccount {
+    /// Poll deciding the proposal
+    pub poll: Pubkey, // 32
+    
+    /// Community the action applies to
+    pub community: Pubkey, // 32
+    
+    /// User who made the proposal (the poll creator)
+    pub proposer: Pubkey, // 32
+    
+    /// Change to apply
+    pub action: ProposalAction, // 1 + max(4 + 200)
+    
+    /// Share of votes that must approve, in basis points
+    pub threshold_bps: u16, // 2
+    
+    /// Seconds after voting closes before the action can be executed
+    pub timelock: i64, // 8
+    
+    /// Timestamp when the proposal was created
+    pub created_at: i64, // 8
+    
+    /// Timestamp when the action was executed
+    pub executed_at: Option<i64>, // 1 + 8
+}
+
+impl ProposalAccount {
+    /// Whether the finalized poll approved the proposal
+    pub fn passed(&self, poll: &PollAccount) -> bool {
+        let approve = poll.tally(0) as u128;
+        let total = approve + poll.tally(1) as u128;
+        poll.is_finalized()
+            && poll.quorum_met
+            && total > 0
+            && approve * 10_000 >= total * self.threshold_bps as u128
+    }
+}
+
+impl PollResultAccount {
+    /// Closes the current round. The leading option wins with a majority of
+    /// non-exhausted ballots; otherwise the weakest option is eliminated
+    /// (ties eliminate the higher index) and the last one standing wins.
+    /// Returns the option eliminated this round, if any.
+    pub fn complete_round(&mut self, now: i64) -> Option<u8> {
+        let option_count = self.current_counts.len();
+        let counts = std::mem::replace(&mut self.current_counts, vec![0; option_count]);
+        let active: u64 = counts.iter().map(|&count| count as u64).sum();
+        let mut remaining: Vec<usize> = (0..option_count)
+            .filter(|&option| self.eliminated & (1 << option) == 0)
+            .collect();
+        
+        let mut eliminated = None;
+        if active == 0 {
+            // Every ballot is exhausted; nobody wins
+            self.completed_at = Some(now);
+        } else {
+            let leader = remaining
+                .iter()
+                .copied()
+                .max_by_key(|&option| (counts[option], std::cmp::Reverse(option)))
+                .unwrap_or_default();
+            
+            if counts[leader] as u64 * 2 > active || remaining.len() == 1 {
+                self.winner = Some(leader as u8);
+                self.completed_at = Some(now);
+            } else {
+                let weakest = remaining
+                    .iter()
+                    .copied()
+                    .min_by_key(|&option| (counts[option], std::cmp::Reverse(option)))
+                    .unwrap_or_default();
+                self.eliminated |= 1 << weakest;
+                remaining.retain(|&option| option != weakest);
+                eliminated = Some(weakest as u8);
+                
+                if let [last] = remaining[..] {
+                    self.winner = Some(last as u8);
+                    self.completed_at = Some(now);
+                }
+            }
+        }
+        
+        self.rounds.push(counts);
+        self.processed_votes = 0;
+        self.exhausted = 0;
+        self.round += 1;
+        
+        eliminated
+    }
+}
+
+/// Reason code attached to a report
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ReportReason {
+    Spam,
+    Harassment,
+    HateSpeech,
+    Violence,
+    Nsfw,
+    Misinformation,
+    Other,
+}
+
+/// Kind of content a report points at
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ReportTarget {
+    Post,
+    Comment,
+}
+
+/// Lifecycle of a report in the moderation queue
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ReportStatus {
+    Open,
+    Dismissed,
+    Removed,
+    AuthorBanned,
+}
+
+/// Moderator decision when resolving a report
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ReportOutcome {
+    /// Report is unfounded; content this report hid is unhidden unless a
+    /// moderator removed it
+    Dismiss,
+    /// Content is hidden
+    Remove,
+    /// Content is hidden and the author is banned from the community
+    BanAuthor,
+}
+
+/// Abuse report
+/// PDA: ["report", target_pubkey, reporter_wallet]
+/// 
+/// A report filed by a community member against a post or comment.
+/// Open reports form the community's moderation queue.
+/// Enforces one-report-per-user-per-target.
+#[account]
+pub struct ReportAccount {
+    /// Community the reported content belongs to
+    pub community: Pubkey, // 32
+    
+    /// Reported post or comment
+    pub target: Pubkey, // 32
+    
+    /// Whether the target is a post or a comment
+    pub target_kind: ReportTarget, // 1
+    
+    /// Author of the reported content (None if anonymous)
+    pub target_author: Option<Pubkey>, // 1 + 32
+    
+    /// User who filed the report
+    pub reporter: Pubkey, // 32
+    
+    /// Reason code
+    pub reason: ReportReason, // 1
+    
+    /// URI to supporting evidence (IPFS/Arweave)
+    pub evidence_uri: String, // 4 + max 200
+    
+    /// Current status
+    pub status: ReportStatus, // 1
+    
+    /// Moderator who resolved the report
+    pub resolved_by: Option<Pubkey>, // 1 + 32
+    
+    /// Timestamp when report was filed
+    pub created_at: i64, // 8
+    
+    /// Timestamp when report was resolved
+    pub resolved_at: Option<i64>, // 1 + 8
+    
+    /// Whether this report crossed the threshold and hid the target
+    pub hid_target: bool, // 1
+}
+
+/// Community ban record
+/// PDA: ["ban", community_pubkey, user_wallet]
+/// 
+/// Prevents a user from (re)joining a community.
+#[account]
+pub struct BanAccount {
+    /// Community the user is banned from
+    pub community: Pubkey, // 32
+    
+    /// User who is banned
+    pub user: Pubkey, // 32
+    
+    /// Moderator who issued the ban
+    pub banned_by: Pubkey, // 32
+    
+    /// Timestamp when ban was issued
+    pub banned_at: i64, // 8
+}
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, state::*};
+
+/// Fails if `author` has blocked `user`
+/// 
+/// `block` must be the ["block", author_profile, user_profile] PDA. Both
+/// profiles are derived from their wallets so callers only pass the block
+/// record itself. An uninitialized block record means no block exists.
+pub fn require_not_blocked(
+    block: Option<&AccountInfo>,
+    author: &Pubkey,
+    user: &Pubkey,
+    program_id: &Pubkey,
+) -> Result<()> {
+    let block = block.ok_or(SocialError::InvalidBlockAccount)?;
+    
+    let (author_profile, _) = Pubkey::find_program_address(&[PROFILE_SEED, author.as_ref()], program_id);
+    let (user_profile, _) = Pubkey::find_program_address(&[PROFILE_SEED, user.as_ref()], program_id);
+    let (expected, _) = Pubkey::find_program_address(
+        &[BLOCK_SEED, author_profile.as_ref(), user_profile.as_ref()],
+        program_id,
+    );
+    
+    require_keys_eq!(block.key(), expected, SocialError::InvalidBlockAccount);
+    require!(block.data_is_empty(), SocialError::BlockedByUser);
+    
+    Ok(())
+}
+
+/// Fails unless `subscription` is an active subscription of `subscriber`
+/// to `creator`
+pub fn require_active_subscription(
+    subscription: Option<&SubscriptionAccount>,
+    creator: &Pubkey,
+    subscriber: &Pubkey,
+    now: i64,
+) -> Result<()> {
+    let subscription = subscription.ok_or(SocialError::SubscriptionRequired)?;
+    
+    require!(
+        subscription.creator == *creator
+            && subscription.subscriber == *subscriber
+            && subscription.is_active(now),
+        SocialError::SubscriptionRequired
+    );
+    
+    Ok(())
+}
+
+/// Closes a program-owned account, refunding its rent to `destination`
+/// 
+/// Used when an account may or may not exist and is therefore passed as an
+/// unchecked PDA rather than a typed account.
+pub fn close_program_account<'info>(
+    account: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+    program_id: &Pubkey,
+) -> Result<()> {
+    require_keys_eq!(*account.owner, *program_id, ErrorCode::AccountOwnedByWrongProgram);
+    
+    let lamports = account.lamports();
+    **destination.try_borrow_mut_lamports()? = destination
+        .lamports()
+        .checked_add(lamports)
+        .ok_or(SocialError::ArithmeticOverflow)?;
+    **account.try_borrow_mut_lamports()? = 0;
+    
+    account.assign(&System::id());
+    account.resize(0)?;
+    
+    Ok(())
+}
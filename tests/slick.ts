@@ -1,8 +1,24 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program, BN } from "@coral-xyz/anchor";
 import { SocialProgram } from "../target/types/social_program";
-import { PublicKey, Keypair, SystemProgram, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import {
+  PublicKey,
+  Keypair,
+  SystemProgram,
+  Transaction,
+  LAMPORTS_PER_SOL,
+} from "@solana/web3.js";
+import {
+  createMint,
+  createAccount,
+  mintTo,
+  getAccount,
+  getAssociatedTokenAddressSync,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
 import { assert, expect } from "chai";
+import { createHash, randomBytes } from "crypto";
 
 describe("social_program", () => {
   const provider = anchor.AnchorProvider.env();
@@ -28,6 +44,7 @@ describe("social_program", () => {
   let follow: PublicKey;
   let poll: PublicKey;
   let vote: PublicKey;
+  let moderator2: PublicKey;
 
   const communityId = new BN(1);
   const contentHash = Array(32).fill(1);
@@ -277,8 +294,10 @@ describe("social_program", () => {
         .joinCommunity()
         .accountsPartial({
           community: community,
+          creator: user1.publicKey,
           userProfile: user2Profile,
           membership: membership2,
+          subscription: null,
           user: user2.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -313,8 +332,10 @@ describe("social_program", () => {
         .joinCommunity()
         .accountsPartial({
           community: community,
+          creator: user1.publicKey,
           userProfile: user2Profile,
           membership: membership2,
+          subscription: null,
           user: user2.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -426,6 +447,8 @@ describe("social_program", () => {
   });
 
   describe("Post Interactions", () => {
+    let tipRecord: PublicKey;
+
     it("User2 likes User1's post", async () => {
       [like] = PublicKey.findProgramAddressSync(
         [Buffer.from("like"), post.toBuffer(), user2.publicKey.toBuffer()],
@@ -438,6 +461,8 @@ describe("social_program", () => {
           post: post,
           membership: membership2,
           like: like,
+          block: findBlock(program.programId, user1.publicKey, user2.publicKey),
+          subscription: null,
           liker: user2.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -465,6 +490,8 @@ describe("social_program", () => {
             post: post,
             membership: membership1,
             like: ownLike,
+            block: findBlock(program.programId, user1.publicKey, user1.publicKey),
+            subscription: null,
             liker: user1.publicKey,
             systemProgram: SystemProgram.programId,
           })
@@ -513,6 +540,8 @@ describe("social_program", () => {
           post: post,
           membership: membership2,
           comment: comment,
+          block: findBlock(program.programId, user1.publicKey, user2.publicKey),
+          subscription: null,
           commenter: user2.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -531,12 +560,19 @@ describe("social_program", () => {
     it("User2 tips the post", async () => {
       const initialBalance = await provider.connection.getBalance(user1.publicKey);
       const tipAmount = 2_000_000; // 0.002 SOL
+      [tipRecord] = PublicKey.findProgramAddressSync(
+        [Buffer.from("tip_record"), post.toBuffer(), user2.publicKey.toBuffer()],
+        program.programId
+      );
 
       await program.methods
-        .tipPost(new BN(tipAmount))
+        .tipPost(new BN(tipAmount), "Great post!")
         .accountsPartial({
           post: post,
           recipient: user1.publicKey,
+          recipientProfile: user1Profile,
+          tipRecord: tipRecord,
+          block: findBlock(program.programId, user1.publicKey, user2.publicKey),
           tipper: user2.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -548,15 +584,107 @@ describe("social_program", () => {
 
       const postData = await program.account.postAccount.fetch(post);
       assert.equal(postData.totalTipLamports.toNumber(), tipAmount);
+
+      const recordData = await program.account.tipRecordAccount.fetch(tipRecord);
+      assert.ok(recordData.tipper.equals(user2.publicKey));
+      assert.equal(recordData.totalLamports.toNumber(), tipAmount);
+      assert.equal(recordData.tipsCount, 1);
+    });
+
+    it("Fails to tip with a memo that is too long", async () => {
+      try {
+        await program.methods
+          .tipPost(new BN(2_000_000), "x".repeat(141))
+          .accountsPartial({
+            post: post,
+            recipient: user1.publicKey,
+            recipientProfile: user1Profile,
+            tipRecord: tipRecord,
+            block: findBlock(program.programId, user1.publicKey, user2.publicKey),
+            tipper: user2.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user2])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("TipMemoTooLong"));
+      }
+    });
+
+    it("User1 tips User2's comment", async () => {
+      const tipAmount = 2_000_000;
+
+      await program.methods
+        .tipComment(new BN(tipAmount), null)
+        .accountsPartial({
+          post: post,
+          comment: comment,
+          recipient: user2.publicKey,
+          recipientProfile: user2Profile,
+          block: findBlock(program.programId, user2.publicKey, user1.publicKey),
+          tipper: user1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+
+      const commentData = await program.account.commentAccount.fetch(comment);
+      assert.equal(commentData.totalTipLamports.toNumber(), tipAmount);
+
+      const profileData = await program.account.profileAccount.fetch(user2Profile);
+      assert.equal(profileData.totalTipsReceived.toNumber(), tipAmount);
+    });
+
+    it("User2 tips User1's profile directly", async () => {
+      const tipAmount = 2_000_000;
+
+      await program.methods
+        .tipProfile(new BN(tipAmount), "Thanks for everything")
+        .accountsPartial({
+          profile: user1Profile,
+          recipient: user1.publicKey,
+          block: findBlock(program.programId, user1.publicKey, user2.publicKey),
+          tipper: user2.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user2])
+        .rpc();
+
+      // One post tip and one profile tip
+      const profileData = await program.account.profileAccount.fetch(user1Profile);
+      assert.equal(profileData.totalTipsReceived.toNumber(), tipAmount * 2);
+    });
+
+    it("Fails to tip your own profile", async () => {
+      try {
+        await program.methods
+          .tipProfile(new BN(2_000_000), null)
+          .accountsPartial({
+            profile: user1Profile,
+            recipient: user1.publicKey,
+            block: findBlock(program.programId, user1.publicKey, user1.publicKey),
+            tipper: user1.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user1])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("CannotTipSelf"));
+      }
     });
 
     it("Fails to tip with wrong amount", async () => {
       try {
         await program.methods
-          .tipPost(new BN(1_000_000)) // Wrong amount
+          .tipPost(new BN(1_000_000), null) // Wrong amount
           .accountsPartial({
             post: post,
             recipient: user1.publicKey,
+            recipientProfile: user1Profile,
+            tipRecord: tipRecord,
+            block: findBlock(program.programId, user1.publicKey, user2.publicKey),
             tipper: user2.publicKey,
             systemProgram: SystemProgram.programId,
           })
@@ -569,194 +697,3884 @@ describe("social_program", () => {
     });
   });
 
-  describe("Poll System", () => {
-    it("Creates a poll", async () => {
-      [poll] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("poll"),
-          community.toBuffer(),
-          new BN(0).toArrayLike(Buffer, "le", 8),
-        ],
+  describe("Reposts", () => {
+    it("User2 reposts User1's post to followers", async () => {
+      const [repost] = PublicKey.findProgramAddressSync(
+        [Buffer.from("repost"), post.toBuffer(), user2.publicKey.toBuffer()],
         program.programId
       );
 
-      const endTime = new BN(Math.floor(Date.now() / 1000) + 86400); // 24 hours from now
-      const options = [user1Profile, user2Profile, user3Profile];
-
       await program.methods
-        .createPoll(
-          "https://example.com/poll1.json",
-          options,
-          endTime
-        )
+        .repost(null, null)
         .accountsPartial({
-          community: community,
-          membership: membership1,
-          poll: poll,
-          creator: user1.publicKey,
+          originalPost: post,
+          destinationMembership: null,
+          repost: repost,
+          reposter: user2.publicKey,
           systemProgram: SystemProgram.programId,
         })
-        .signers([user1])
+        .signers([user2])
         .rpc();
 
-      const pollData = await program.account.pollAccount.fetch(poll);
-      assert.equal(pollData.questionUri, "https://example.com/poll1.json");
-      assert.equal(pollData.optionProfiles.length, 3);
-      assert.equal(pollData.votesPerOption.length, 3);
-      assert.equal(pollData.votesPerOption[0], 0);
+      const repostData = await program.account.repostAccount.fetch(repost);
+      assert.equal(repostData.originalPost.toString(), post.toString());
+      assert.equal(repostData.reposter.toString(), user2.publicKey.toString());
+      assert.isNull(repostData.community);
+      assert.isNull(repostData.quoteUri);
+
+      const postData = await program.account.postAccount.fetch(post);
+      assert.equal(postData.repostsCount.toNumber(), 1);
     });
 
-    it("User2 votes on poll", async () => {
-      [vote] = PublicKey.findProgramAddressSync(
-        [Buffer.from("vote"), poll.toBuffer(), user2.publicKey.toBuffer()],
+    it("User1 quote-reposts into the community", async () => {
+      const [repost] = PublicKey.findProgramAddressSync(
+        [Buffer.from("repost"), post.toBuffer(), user1.publicKey.toBuffer()],
         program.programId
       );
 
       await program.methods
-        .votePoll(1) // Vote for option 1 (user2Profile)
+        .repost("https://example.com/quote1.json", contentHash)
         .accountsPartial({
-          poll: poll,
-          membership: membership2,
-          vote: vote,
-          voter: user2.publicKey,
+          originalPost: post,
+          destinationMembership: membership1,
+          repost: repost,
+          reposter: user1.publicKey,
           systemProgram: SystemProgram.programId,
         })
-        .signers([user2])
+        .signers([user1])
         .rpc();
 
-      const pollData = await program.account.pollAccount.fetch(poll);
-      assert.equal(pollData.votesPerOption[1], 1);
+      const repostData = await program.account.repostAccount.fetch(repost);
+      assert.equal(repostData.community.toString(), community.toString());
+      assert.equal(repostData.quoteUri, "https://example.com/quote1.json");
 
-      const voteData = await program.account.voteAccount.fetch(vote);
-      assert.equal(voteData.poll.toString(), poll.toString());
-      assert.equal(voteData.voter.toString(), user2.publicKey.toString());
-      assert.equal(voteData.optionIndex, 1);
+      const postData = await program.account.postAccount.fetch(post);
+      assert.equal(postData.repostsCount.toNumber(), 2);
     });
 
-    it("Fails to vote twice", async () => {
+    it("Fails to repost into a community using someone else's membership", async () => {
+      const [repost] = PublicKey.findProgramAddressSync(
+        [Buffer.from("repost"), post.toBuffer(), user3.publicKey.toBuffer()],
+        program.programId
+      );
+
       try {
         await program.methods
-          .votePoll(2)
+          .repost(null, null)
           .accountsPartial({
-            poll: poll,
-            membership: membership2,
-            vote: vote,
-            voter: user2.publicKey,
+            originalPost: post,
+            destinationMembership: membership1,
+            repost: repost,
+            reposter: user3.publicKey,
             systemProgram: SystemProgram.programId,
           })
-          .signers([user2])
+          .signers([user3])
           .rpc();
         assert.fail("Should have thrown error");
       } catch (err) {
-        assert.ok(err.toString().includes("already in use"));
+        assert.ok(err.toString().includes("NotCommunityMember"));
       }
     });
 
-    it("Fails to vote with invalid option", async () => {
-      const [user3Vote] = PublicKey.findProgramAddressSync(
-        [Buffer.from("vote"), poll.toBuffer(), user1.publicKey.toBuffer()],
+    it("Fails to quote without a content hash", async () => {
+      const [repost] = PublicKey.findProgramAddressSync(
+        [Buffer.from("repost"), post.toBuffer(), user3.publicKey.toBuffer()],
         program.programId
       );
 
       try {
         await program.methods
-          .votePoll(10) // Invalid option
+          .repost("https://example.com/quote2.json", null)
           .accountsPartial({
-            poll: poll,
-            membership: membership1,
-            vote: user3Vote,
-            voter: user1.publicKey,
+            originalPost: post,
+            destinationMembership: null,
+            repost: repost,
+            reposter: user3.publicKey,
             systemProgram: SystemProgram.programId,
           })
-          .signers([user1])
+          .signers([user3])
           .rpc();
         assert.fail("Should have thrown error");
       } catch (err) {
-        assert.ok(err.toString().includes("InvalidPollOption"));
+        assert.ok(err.toString().includes("InvalidQuote"));
       }
     });
   });
 
-  describe("Edge Cases and Security", () => {
-    it("Non-member cannot create post", async () => {
-      const [nonMemberPost] = PublicKey.findProgramAddressSync(
+  describe("Profile Posts", () => {
+    let profilePost: PublicKey;
+
+    it("User1 creates a post on their profile", async () => {
+      [profilePost] = PublicKey.findProgramAddressSync(
         [
           Buffer.from("post"),
-          community.toBuffer(),
-          new BN(10).toArrayLike(Buffer, "le", 8),
+          user1Profile.toBuffer(),
+          new BN(0).toArrayLike(Buffer, "le", 8),
         ],
         program.programId
       );
 
-      const [fakeMembership] = PublicKey.findProgramAddressSync(
-        [Buffer.from("membership"), community.toBuffer(), user3.publicKey.toBuffer()],
+      await program.methods
+        .createProfilePost("https://example.com/profile-post1.json", contentHash)
+        .accountsPartial({
+          profile: user1Profile,
+          post: profilePost,
+          author: user1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+
+      const postData = await program.account.postAccount.fetch(profilePost);
+      assert.equal(postData.community.toString(), user1Profile.toString());
+      assert.equal(postData.author.toString(), user1.publicKey.toString());
+      assert.ok(postData.scope.profile !== undefined);
+
+      const profile = await program.account.profileAccount.fetch(user1Profile);
+      assert.equal(profile.postCounter.toNumber(), 1);
+    });
+
+    it("User3 likes and comments on a profile post without membership", async () => {
+      const [profileLike] = PublicKey.findProgramAddressSync(
+        [Buffer.from("like"), profilePost.toBuffer(), user3.publicKey.toBuffer()],
+        program.programId
+      );
+      const [profileComment] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("comment"),
+          profilePost.toBuffer(),
+          new BN(0).toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .likePost()
+        .accountsPartial({
+          post: profilePost,
+          membership: null,
+          like: profileLike,
+          block: findBlock(program.programId, user1.publicKey, user3.publicKey),
+          subscription: null,
+          liker: user3.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user3])
+        .rpc();
+
+      await program.methods
+        .commentOnPost("https://example.com/profile-comment1.json", contentHash)
+        .accountsPartial({
+          post: profilePost,
+          membership: null,
+          comment: profileComment,
+          block: findBlock(program.programId, user1.publicKey, user3.publicKey),
+          subscription: null,
+          commenter: user3.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user3])
+        .rpc();
+
+      const postData = await program.account.postAccount.fetch(profilePost);
+      assert.equal(postData.likesCount.toNumber(), 1);
+      assert.equal(postData.commentsCount.toNumber(), 1);
+    });
+
+    it("Fails to like a community post without membership", async () => {
+      const [badLike] = PublicKey.findProgramAddressSync(
+        [Buffer.from("like"), post.toBuffer(), user3.publicKey.toBuffer()],
         program.programId
       );
 
       try {
         await program.methods
-          .createPost(
-            "https://example.com/post-bad.json",
-            contentHash,
-            false,
-            null
-          )
+          .likePost()
           .accountsPartial({
-            community: community,
-            membership: fakeMembership,
-            post: nonMemberPost,
-            author: user3.publicKey,
+            post: post,
+            membership: null,
+            like: badLike,
+            block: findBlock(program.programId, user1.publicKey, user3.publicKey),
+            subscription: null,
+            liker: user3.publicKey,
             systemProgram: SystemProgram.programId,
           })
           .signers([user3])
           .rpc();
         assert.fail("Should have thrown error");
       } catch (err) {
-        assert.ok(err.message.includes("AccountNotInitialized") || err.message.includes("AnchorError"));
+        assert.ok(err.toString().includes("NotCommunityMember"));
       }
     });
+  });
 
-    it("Content URI too long fails", async () => {
-      const longUri = "https://example.com/" + "a".repeat(200);
-      const [longPost] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("post"),
-          community.toBuffer(),
-          new BN(11).toArrayLike(Buffer, "le", 8),
-        ],
+  describe("Moderators and Pinned Posts", () => {
+    it("Creator adds User2 as a moderator", async () => {
+      [moderator2] = PublicKey.findProgramAddressSync(
+        [Buffer.from("moderator"), community.toBuffer(), user2.publicKey.toBuffer()],
         program.programId
       );
 
+      await program.methods
+        .addModerator()
+        .accountsPartial({
+          community: community,
+          membership: membership2,
+          moderator: moderator2,
+          creator: user1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+
+      const moderatorData = await program.account.moderatorAccount.fetch(moderator2);
+      assert.equal(moderatorData.user.toString(), user2.publicKey.toString());
+      assert.equal(moderatorData.community.toString(), community.toString());
+    });
+
+    it("Moderator pins a community post", async () => {
+      await program.methods
+        .pinPost()
+        .accountsPartial({
+          community: community,
+          moderator: moderator2,
+          post: post,
+          authority: user2.publicKey,
+        })
+        .signers([user2])
+        .rpc();
+
+      const communityData = await program.account.communityAccount.fetch(community);
+      assert.equal(communityData.pinnedPosts.length, 1);
+      assert.equal(communityData.pinnedPosts[0].toString(), post.toString());
+    });
+
+    it("Fails to pin the same post twice", async () => {
       try {
         await program.methods
-          .createPost(longUri, contentHash, false, null)
+          .pinPost()
           .accountsPartial({
             community: community,
-            membership: membership1,
-            post: longPost,
-            author: user1.publicKey,
-            systemProgram: SystemProgram.programId,
+            moderator: null,
+            post: post,
+            authority: user1.publicKey,
           })
           .signers([user1])
           .rpc();
         assert.fail("Should have thrown error");
       } catch (err) {
-        // The error could be ContentUriTooLong or a serialization error
-        assert.ok(err.toString().includes("ContentUriTooLong") || err.toString().includes("Error Code"));
+        assert.ok(err.toString().includes("PostAlreadyPinned"));
       }
     });
-  });
-});
 
-// Helper function to airdrop SOL
-async function airdrop(connection: any, address: PublicKey, amount: number) {
-  const signature = await connection.requestAirdrop(
-    address,
-    amount * LAMPORTS_PER_SOL
-  );
-  const latestBlockhash = await connection.getLatestBlockhash();
-  await connection.confirmTransaction({
-    signature,
-    ...latestBlockhash,
-  });
-}
\ No newline at end of file
+    it("Non-moderator cannot unpin", async () => {
+      try {
+        await program.methods
+          .unpinPost()
+          .accountsPartial({
+            community: community,
+            moderator: null,
+            post: post,
+            authority: user3.publicKey,
+          })
+          .signers([user3])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("NotModerator"));
+      }
+    });
+
+    it("Moderator unpins the post", async () => {
+      await program.methods
+        .unpinPost()
+        .accountsPartial({
+          community: community,
+          moderator: moderator2,
+          post: post,
+          authority: user2.publicKey,
+        })
+        .signers([user2])
+        .rpc();
+
+      const communityData = await program.account.communityAccount.fetch(community);
+      assert.equal(communityData.pinnedPosts.length, 0);
+    });
+
+    it("User1 pins their own post on their profile", async () => {
+      await program.methods
+        .pinProfilePost()
+        .accountsPartial({
+          profile: user1Profile,
+          post: post,
+          owner: user1.publicKey,
+        })
+        .signers([user1])
+        .rpc();
+
+      const profile = await program.account.profileAccount.fetch(user1Profile);
+      assert.equal(profile.pinnedPosts[0].toString(), post.toString());
+    });
+
+    it("Fails to pin someone else's post on a profile", async () => {
+      try {
+        await program.methods
+          .pinProfilePost()
+          .accountsPartial({
+            profile: user3Profile,
+            post: post,
+            owner: user3.publicKey,
+          })
+          .signers([user3])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("NotPostAuthor"));
+      }
+    });
+  });
+
+  describe("Post Locking", () => {
+    it("Author locks their post", async () => {
+      await program.methods
+        .lockPost(null)
+        .accountsPartial({
+          post: post,
+          community: null,
+          moderator: null,
+          authority: user1.publicKey,
+        })
+        .signers([user1])
+        .rpc();
+
+      const postData = await program.account.postAccount.fetch(post);
+      assert.isTrue(postData.locked);
+      assert.isNull(postData.lockedUntil);
+    });
+
+    it("Fails to comment on a locked post", async () => {
+      const postData = await program.account.postAccount.fetch(post);
+      const [lockedComment] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("comment"),
+          post.toBuffer(),
+          postData.commentsCount.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .commentOnPost("https://example.com/comment-locked.json", contentHash)
+          .accountsPartial({
+            post: post,
+            membership: membership2,
+            comment: lockedComment,
+            block: findBlock(program.programId, user1.publicKey, user2.publicKey),
+            subscription: null,
+            commenter: user2.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user2])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("PostLocked"));
+      }
+    });
+
+    it("Non-moderator cannot unlock the post", async () => {
+      try {
+        await program.methods
+          .unlockPost()
+          .accountsPartial({
+            post: post,
+            community: community,
+            moderator: null,
+            authority: user3.publicKey,
+          })
+          .signers([user3])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("NotAuthorOrModerator"));
+      }
+    });
+
+    it("Moderator unlocks the post", async () => {
+      await program.methods
+        .unlockPost()
+        .accountsPartial({
+          post: post,
+          community: community,
+          moderator: moderator2,
+          authority: user2.publicKey,
+        })
+        .signers([user2])
+        .rpc();
+
+      const postData = await program.account.postAccount.fetch(post);
+      assert.isFalse(postData.locked);
+    });
+  });
+
+  describe("Bounties", () => {
+    let bounty: PublicKey;
+    const bountyAmount = 0.01 * LAMPORTS_PER_SOL;
+
+    const createBounty = (expiresAt: number) =>
+      program.methods
+        .createBounty(new BN(bountyAmount), new BN(expiresAt))
+        .accountsPartial({
+          post: post,
+          bounty: bounty,
+          author: user1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+
+    const award = (author: Keypair) =>
+      program.methods
+        .awardBounty()
+        .accountsPartial({
+          post: post,
+          bounty: bounty,
+          comment: comment,
+          recipient: user2.publicKey,
+          author: author.publicKey,
+        })
+        .signers([author])
+        .rpc();
+
+    const refund = () =>
+      program.methods
+        .refundBounty()
+        .accountsPartial({
+          post: post,
+          bounty: bounty,
+          author: user1.publicKey,
+        })
+        .rpc();
+
+    it("Author attaches a bounty to a post", async () => {
+      [bounty] = PublicKey.findProgramAddressSync(
+        [Buffer.from("bounty"), post.toBuffer()],
+        program.programId
+      );
+
+      await createBounty(Math.floor(Date.now() / 1000) + 3600);
+
+      const bountyData = await program.account.bountyAccount.fetch(bounty);
+      assert.equal(bountyData.amount.toNumber(), bountyAmount);
+      assert.ok(bountyData.author.equals(user1.publicKey));
+
+      const postData = await program.account.postAccount.fetch(post);
+      assert.equal(postData.bountyLamports.toNumber(), bountyAmount);
+    });
+
+    it("Fails to refund a bounty before it expires", async () => {
+      try {
+        await refund();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("BountyNotExpired"));
+      }
+    });
+
+    it("Only the post author can award the bounty", async () => {
+      try {
+        await award(user3);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("NotPostAuthor"));
+      }
+    });
+
+    it("Author awards the bounty to a commenter", async () => {
+      const before = await provider.connection.getBalance(user2.publicKey);
+      await award(user1);
+      const after = await provider.connection.getBalance(user2.publicKey);
+
+      assert.equal(after - before, bountyAmount);
+      assert.isNull(await provider.connection.getAccountInfo(bounty));
+
+      const postData = await program.account.postAccount.fetch(post);
+      assert.equal(postData.bountyLamports.toNumber(), 0);
+    });
+
+    it("Refunds an expired bounty to the author", async () => {
+      await createBounty(Math.floor(Date.now() / 1000) + 3);
+      await sleep(5000);
+
+      try {
+        await award(user1);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("BountyExpired"));
+      }
+
+      const before = await provider.connection.getBalance(user1.publicKey);
+      await refund();
+      const after = await provider.connection.getBalance(user1.publicKey);
+
+      assert.isAbove(after - before, bountyAmount);
+      assert.isNull(await provider.connection.getAccountInfo(bounty));
+    });
+  });
+
+  describe("Paid Posts", () => {
+    const price = 0.005 * LAMPORTS_PER_SOL;
+
+    const unlockFor = (buyer: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("unlock"), post.toBuffer(), buyer.toBuffer()],
+        program.programId
+      )[0];
+
+    const purchase = (buyer: Keypair, expectedPrice: number) =>
+      program.methods
+        .purchasePost(new BN(expectedPrice))
+        .accountsPartial({
+          post: post,
+          unlock: unlockFor(buyer.publicKey),
+          author: user1.publicKey,
+          block: findBlock(program.programId, user1.publicKey, buyer.publicKey),
+          buyer: buyer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyer])
+        .rpc();
+
+    const setPrice = (author: Keypair, priceLamports: number) =>
+      program.methods
+        .setPostPrice(new BN(priceLamports))
+        .accountsPartial({ post: post, author: author.publicKey })
+        .signers([author])
+        .rpc();
+
+    it("Only the author can price a post", async () => {
+      try {
+        await setPrice(user2, price);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("NotPostAuthor"));
+      }
+    });
+
+    it("Author sets a price", async () => {
+      await setPrice(user1, price);
+
+      const postData = await program.account.postAccount.fetch(post);
+      assert.equal(postData.priceLamports.toNumber(), price);
+    });
+
+    it("Fails to purchase at a stale price", async () => {
+      try {
+        await purchase(user2, price / 2);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("PostPriceChanged"));
+      }
+    });
+
+    it("Buyer purchases the post and gets a receipt", async () => {
+      const before = await provider.connection.getBalance(user1.publicKey);
+      await purchase(user2, price);
+      const after = await provider.connection.getBalance(user1.publicKey);
+
+      assert.equal(after - before, price);
+
+      const unlockData = await program.account.unlockAccount.fetch(unlockFor(user2.publicKey));
+      assert.ok(unlockData.post.equals(post));
+      assert.ok(unlockData.buyer.equals(user2.publicKey));
+      assert.equal(unlockData.pricePaid.toNumber(), price);
+
+      const postData = await program.account.postAccount.fetch(post);
+      assert.equal(postData.unlocksCount.toNumber(), 1);
+    });
+
+    it("Fails to purchase the same post twice", async () => {
+      try {
+        await purchase(user2, price);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("already in use"));
+      }
+    });
+
+    it("Free posts cannot be purchased", async () => {
+      await setPrice(user1, 0);
+
+      try {
+        await purchase(user3, 0);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("PostNotForSale"));
+      }
+    });
+  });
+
+  describe("Subscriptions", () => {
+    let profilePost: PublicKey;
+    let subscription: PublicKey;
+    const tierPrice = 0.001 * LAMPORTS_PER_SOL;
+
+    const subscribe = (tier: number, price: number) =>
+      program.methods
+        .subscribe(tier, new BN(price))
+        .accountsPartial({
+          creatorProfile: user1Profile,
+          creator: user1.publicKey,
+          subscription,
+          block: findBlock(program.programId, user1.publicKey, user3.publicKey),
+          subscriber: user3.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user3])
+        .rpc();
+
+    const comment = async (withSubscription: boolean) => {
+      const postData = await program.account.postAccount.fetch(profilePost);
+      const [profileComment] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("comment"),
+          profilePost.toBuffer(),
+          postData.commentsCount.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .commentOnPost("https://example.com/subscriber-comment.json", contentHash)
+        .accountsPartial({
+          post: profilePost,
+          membership: null,
+          comment: profileComment,
+          block: findBlock(program.programId, user1.publicKey, user3.publicKey),
+          subscription: withSubscription ? subscription : null,
+          commenter: user3.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user3])
+        .rpc();
+    };
+
+    it("Fails to publish too many tiers", async () => {
+      const tier = { priceLamports: new BN(tierPrice), period: new BN(3600) };
+
+      try {
+        await program.methods
+          .setSubscriptionTiers([tier, tier, tier, tier])
+          .accountsPartial({ profile: user1Profile, owner: user1.publicKey })
+          .signers([user1])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("TooManySubscriptionTiers"));
+      }
+    });
+
+    it("Creator publishes subscription tiers", async () => {
+      await program.methods
+        .setSubscriptionTiers([
+          { priceLamports: new BN(tierPrice), period: new BN(3600) },
+          { priceLamports: new BN(tierPrice * 3), period: new BN(86400) },
+        ])
+        .accountsPartial({ profile: user1Profile, owner: user1.publicKey })
+        .signers([user1])
+        .rpc();
+
+      const profileData = await program.account.profileAccount.fetch(user1Profile);
+      assert.equal(profileData.subscriptionTiers.length, 2);
+      assert.equal(profileData.subscriptionTiers[1].period.toNumber(), 86400);
+    });
+
+    it("Author restricts a post to subscribers", async () => {
+      [profilePost] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("post"),
+          user1Profile.toBuffer(),
+          new BN(0).toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      [subscription] = PublicKey.findProgramAddressSync(
+        [Buffer.from("subscription"), user1.publicKey.toBuffer(), user3.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .setPostAccess(true)
+        .accountsPartial({ post: profilePost, author: user1.publicKey })
+        .signers([user1])
+        .rpc();
+
+      try {
+        await comment(false);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("SubscriptionRequired"));
+      }
+    });
+
+    it("Fails to subscribe at a stale price", async () => {
+      try {
+        await subscribe(0, tierPrice * 2);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("SubscriptionPriceChanged"));
+      }
+    });
+
+    it("Subscriber pays the creator and can comment", async () => {
+      const before = await provider.connection.getBalance(user1.publicKey);
+      await subscribe(0, tierPrice);
+      const after = await provider.connection.getBalance(user1.publicKey);
+      assert.equal(after - before, tierPrice);
+
+      const subscriptionData = await program.account.subscriptionAccount.fetch(subscription);
+      assert.ok(subscriptionData.creator.equals(user1.publicKey));
+      assert.equal(
+        subscriptionData.expiresAt.toNumber() - subscriptionData.startedAt.toNumber(),
+        3600
+      );
+
+      await comment(true);
+    });
+
+    it("Renewing extends from the current expiry", async () => {
+      const before = await program.account.subscriptionAccount.fetch(subscription);
+      await subscribe(1, tierPrice * 3);
+      const after = await program.account.subscriptionAccount.fetch(subscription);
+
+      assert.equal(after.tier, 1);
+      assert.equal(after.expiresAt.toNumber(), before.expiresAt.toNumber() + 86400);
+      assert.equal(after.startedAt.toNumber(), before.startedAt.toNumber());
+      assert.equal(after.totalPaid.toNumber(), tierPrice * 4);
+    });
+
+    it("Subscriber cancels", async () => {
+      await program.methods
+        .cancelSubscription()
+        .accountsPartial({ subscription, subscriber: user3.publicKey })
+        .signers([user3])
+        .rpc();
+
+      assert.isNull(await provider.connection.getAccountInfo(subscription));
+
+      await program.methods
+        .setPostAccess(false)
+        .accountsPartial({ post: profilePost, author: user1.publicKey })
+        .signers([user1])
+        .rpc();
+    });
+  });
+
+  describe("Post NFTs", () => {
+    const TOKEN_METADATA_PROGRAM_ID = new PublicKey(
+      "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
+    );
+
+    const mintPostNft = (author: Keypair) => {
+      const [mint] = PublicKey.findProgramAddressSync(
+        [Buffer.from("post_mint"), post.toBuffer()],
+        program.programId
+      );
+      const [metadata] = PublicKey.findProgramAddressSync(
+        [Buffer.from("metadata"), TOKEN_METADATA_PROGRAM_ID.toBuffer(), mint.toBuffer()],
+        TOKEN_METADATA_PROGRAM_ID
+      );
+      const [masterEdition] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("metadata"),
+          TOKEN_METADATA_PROGRAM_ID.toBuffer(),
+          mint.toBuffer(),
+          Buffer.from("edition"),
+        ],
+        TOKEN_METADATA_PROGRAM_ID
+      );
+
+      return program.methods
+        .mintPostNft("Slick Post #0", "SLICK", 500)
+        .accountsPartial({
+          post: post,
+          mint,
+          authorTokenAccount: getAssociatedTokenAddressSync(mint, author.publicKey),
+          metadata,
+          masterEdition,
+          author: author.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([author])
+        .rpc();
+    };
+
+    it("Only the author can mint a post", async () => {
+      try {
+        await mintPostNft(user2);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("NotPostAuthor"));
+      }
+    });
+
+    it("Author mints the post as an NFT", async () => {
+      await mintPostNft(user1);
+
+      const postData = await program.account.postAccount.fetch(post);
+      const tokens = await getAccount(
+        provider.connection,
+        getAssociatedTokenAddressSync(postData.nftMint, user1.publicKey)
+      );
+      assert.equal(Number(tokens.amount), 1);
+    });
+
+    it("Fails to mint the same post twice", async () => {
+      try {
+        await mintPostNft(user1);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("already in use"));
+      }
+    });
+  });
+
+  describe("Reports and Moderation Queue", () => {
+    let anonPost: PublicKey;
+    let postReport: PublicKey;
+    let commentReport: PublicKey;
+
+    before(() => {
+      [anonPost] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("post"),
+          community.toBuffer(),
+          new BN(1).toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+    });
+
+    it("Creator sets the report threshold", async () => {
+      await program.methods
+        .setReportThreshold(1)
+        .accounts({
+          community: community,
+          creator: user1.publicKey,
+        })
+        .signers([user1])
+        .rpc();
+
+      const communityData = await program.account.communityAccount.fetch(community);
+      assert.equal(communityData.reportThreshold, 1);
+    });
+
+    it("Reporting a comment past the threshold hides it", async () => {
+      [commentReport] = PublicKey.findProgramAddressSync(
+        [Buffer.from("report"), comment.toBuffer(), user1.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .reportComment({ spam: {} }, "https://example.com/evidence1.json")
+        .accountsPartial({
+          community: community,
+          post: post,
+          comment: comment,
+          membership: membership1,
+          report: commentReport,
+          reporter: user1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+
+      const reportData = await program.account.reportAccount.fetch(commentReport);
+      assert.equal(reportData.target.toString(), comment.toString());
+      assert.equal(reportData.targetAuthor.toString(), user2.publicKey.toString());
+      assert.ok(reportData.status.open !== undefined);
+      assert.isTrue(reportData.hidTarget);
+
+      const commentData = await program.account.commentAccount.fetch(comment);
+      assert.equal(commentData.reportsCount, 1);
+      assert.isTrue(commentData.hidden);
+    });
+
+    it("Moderator dismisses the comment report", async () => {
+      await program.methods
+        .resolveReport({ dismiss: {} })
+        .accountsPartial({
+          community: community,
+          moderator: moderator2,
+          report: commentReport,
+          post: null,
+          comment: comment,
+          ban: null,
+          authorMembership: null,
+          authorModerator: null,
+          author: null,
+          authority: user2.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user2])
+        .rpc();
+
+      const reportData = await program.account.reportAccount.fetch(commentReport);
+      assert.ok(reportData.status.dismissed !== undefined);
+      assert.equal(reportData.resolvedBy.toString(), user2.publicKey.toString());
+
+      const commentData = await program.account.commentAccount.fetch(comment);
+      assert.isFalse(commentData.hidden);
+    });
+
+    it("Fails to resolve the same report twice", async () => {
+      try {
+        await program.methods
+          .resolveReport({ remove: {} })
+          .accountsPartial({
+            community: community,
+            moderator: null,
+            report: commentReport,
+            post: null,
+            comment: comment,
+            ban: null,
+            authorMembership: null,
+            authorModerator: null,
+            author: null,
+            authority: user1.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user1])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("ReportAlreadyResolved"));
+      }
+    });
+
+    it("Cannot ban the author of an anonymous post", async () => {
+      [postReport] = PublicKey.findProgramAddressSync(
+        [Buffer.from("report"), anonPost.toBuffer(), user1.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .reportPost({ harassment: {} }, "https://example.com/evidence2.json")
+        .accountsPartial({
+          community: community,
+          post: anonPost,
+          membership: membership1,
+          report: postReport,
+          reporter: user1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+
+      try {
+        await program.methods
+          .resolveReport({ banAuthor: {} })
+          .accountsPartial({
+            community: community,
+            moderator: null,
+            report: postReport,
+            post: anonPost,
+            comment: null,
+            ban: null,
+            authorMembership: null,
+            authorModerator: null,
+            author: null,
+            authority: user1.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user1])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("CannotBanAnonymousAuthor"));
+      }
+    });
+
+    it("Creator removes the anonymous post", async () => {
+      await program.methods
+        .resolveReport({ remove: {} })
+        .accountsPartial({
+          community: community,
+          moderator: null,
+          report: postReport,
+          post: anonPost,
+          comment: null,
+          ban: null,
+          authorMembership: null,
+          authorModerator: null,
+          author: null,
+          authority: user1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+
+      const postData = await program.account.postAccount.fetch(anonPost);
+      assert.isTrue(postData.hidden);
+      assert.isTrue(postData.removed);
+      assert.equal(postData.reportsCount, 1);
+    });
+
+    it("Banning a moderator closes their membership and moderator record", async () => {
+      const user4 = Keypair.generate();
+      await airdrop(provider.connection, user4.publicKey, 2);
+
+      const [user4Profile] = PublicKey.findProgramAddressSync(
+        [Buffer.from("profile"), user4.publicKey.toBuffer()],
+        program.programId
+      );
+      const [membership4] = PublicKey.findProgramAddressSync(
+        [Buffer.from("membership"), community.toBuffer(), user4.publicKey.toBuffer()],
+        program.programId
+      );
+      const [moderator4] = PublicKey.findProgramAddressSync(
+        [Buffer.from("moderator"), community.toBuffer(), user4.publicKey.toBuffer()],
+        program.programId
+      );
+      const [ban4] = PublicKey.findProgramAddressSync(
+        [Buffer.from("ban"), community.toBuffer(), user4.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .createProfile("Dave", "https://example.com/dave.jpg")
+        .accountsPartial({
+          profile: user4Profile,
+          owner: user4.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user4])
+        .rpc();
+      await program.methods
+        .joinCommunity()
+        .accountsPartial({
+          community: community,
+          creator: user1.publicKey,
+          userProfile: user4Profile,
+          membership: membership4,
+          subscription: null,
+          user: user4.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user4])
+        .rpc();
+      await program.methods
+        .addModerator()
+        .accountsPartial({
+          community: community,
+          membership: membership4,
+          moderator: moderator4,
+          creator: user1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+
+      const communityData = await program.account.communityAccount.fetch(community);
+      const [modPost] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("post"),
+          community.toBuffer(),
+          communityData.postCounter.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      await program.methods
+        .createPost("https://example.com/mod-post.json", contentHash, false, null)
+        .accountsPartial({
+          community: community,
+          membership: membership4,
+          post: modPost,
+          author: user4.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user4])
+        .rpc();
+
+      const [modPostReport] = PublicKey.findProgramAddressSync(
+        [Buffer.from("report"), modPost.toBuffer(), user1.publicKey.toBuffer()],
+        program.programId
+      );
+      await program.methods
+        .reportPost({ harassment: {} }, "https://example.com/evidence3.json")
+        .accountsPartial({
+          community: community,
+          post: modPost,
+          membership: membership1,
+          report: modPostReport,
+          reporter: user1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+
+      await program.methods
+        .resolveReport({ banAuthor: {} })
+        .accountsPartial({
+          community: community,
+          moderator: null,
+          report: modPostReport,
+          post: modPost,
+          comment: null,
+          ban: ban4,
+          authorMembership: membership4,
+          authorModerator: moderator4,
+          author: user4.publicKey,
+          authority: user1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+
+      const reportData = await program.account.reportAccount.fetch(modPostReport);
+      assert.ok(reportData.status.authorBanned !== undefined);
+      assert.isNull(await provider.connection.getAccountInfo(membership4));
+      assert.isNull(await provider.connection.getAccountInfo(moderator4));
+
+      const banData = await program.account.banAccount.fetch(ban4);
+      assert.equal(banData.user.toString(), user4.publicKey.toString());
+    });
+  });
+
+  describe("Blocking", () => {
+    let block: PublicKey;
+    let user3Follow: PublicKey;
+
+    it("Blocking tears down an existing follow", async () => {
+      [user3Follow] = PublicKey.findProgramAddressSync(
+        [Buffer.from("follow"), user3Profile.toBuffer(), user1Profile.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .followUser()
+        .accountsPartial({
+          followerProfile: user3Profile,
+          followedProfile: user1Profile,
+          follow: user3Follow,
+          owner: user3.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user3])
+        .rpc();
+
+      let user1ProfileData = await program.account.profileAccount.fetch(user1Profile);
+      const followersBefore = user1ProfileData.followerCount.toNumber();
+
+      block = findBlock(program.programId, user1.publicKey, user3.publicKey);
+
+      await program.methods
+        .blockUser()
+        .accountsPartial({
+          blockerProfile: user1Profile,
+          blockedProfile: user3Profile,
+          block: block,
+          blockedOwner: user3.publicKey,
+          owner: user1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+
+      const blockData = await program.account.blockAccount.fetch(block);
+      assert.equal(blockData.blocker.toString(), user1Profile.toString());
+      assert.equal(blockData.blocked.toString(), user3Profile.toString());
+
+      user1ProfileData = await program.account.profileAccount.fetch(user1Profile);
+      assert.equal(user1ProfileData.followerCount.toNumber(), followersBefore - 1);
+
+      const followAccount = await provider.connection.getAccountInfo(user3Follow);
+      assert.isNull(followAccount);
+    });
+
+    it("Blocked user cannot follow the blocker", async () => {
+      try {
+        await program.methods
+          .followUser()
+          .accountsPartial({
+            followerProfile: user3Profile,
+            followedProfile: user1Profile,
+            follow: user3Follow,
+            owner: user3.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user3])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("BlockedByUser"));
+      }
+    });
+
+    it("Blocked user cannot comment on the blocker's posts", async () => {
+      const [profilePost] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("post"),
+          user1Profile.toBuffer(),
+          new BN(0).toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      const postData = await program.account.postAccount.fetch(profilePost);
+      const [blockedComment] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("comment"),
+          profilePost.toBuffer(),
+          postData.commentsCount.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .commentOnPost("https://example.com/comment-blocked.json", contentHash)
+          .accountsPartial({
+            post: profilePost,
+            membership: null,
+            comment: blockedComment,
+            block: block,
+            subscription: null,
+            commenter: user3.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user3])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("BlockedByUser"));
+      }
+    });
+
+    it("User1 unblocks User3", async () => {
+      await program.methods
+        .unblockUser()
+        .accountsPartial({
+          blockerProfile: user1Profile,
+          blockedProfile: user3Profile,
+          block: block,
+          owner: user1.publicKey,
+        })
+        .signers([user1])
+        .rpc();
+
+      const blockAccount = await provider.connection.getAccountInfo(block);
+      assert.isNull(blockAccount);
+    });
+  });
+
+  describe("Follow Requests", () => {
+    let user3Request: PublicKey;
+    let user1Request: PublicKey;
+
+    it("User2 makes their profile private", async () => {
+      await program.methods
+        .setProfilePrivacy(true)
+        .accounts({
+          profile: user2Profile,
+          owner: user2.publicKey,
+        })
+        .signers([user2])
+        .rpc();
+
+      const profile = await program.account.profileAccount.fetch(user2Profile);
+      assert.isTrue(profile.requiresApproval);
+    });
+
+    it("Fails to follow a private profile directly", async () => {
+      const [directFollow] = PublicKey.findProgramAddressSync(
+        [Buffer.from("follow"), user3Profile.toBuffer(), user2Profile.toBuffer()],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .followUser()
+          .accountsPartial({
+            followerProfile: user3Profile,
+            followedProfile: user2Profile,
+            follow: directFollow,
+            owner: user3.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user3])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("FollowRequiresApproval"));
+      }
+    });
+
+    it("User3 requests to follow and User2 approves", async () => {
+      [user3Request] = PublicKey.findProgramAddressSync(
+        [Buffer.from("follow_request"), user3Profile.toBuffer(), user2Profile.toBuffer()],
+        program.programId
+      );
+      const [approvedFollow] = PublicKey.findProgramAddressSync(
+        [Buffer.from("follow"), user3Profile.toBuffer(), user2Profile.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .requestFollow()
+        .accountsPartial({
+          followerProfile: user3Profile,
+          followedProfile: user2Profile,
+          followRequest: user3Request,
+          owner: user3.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user3])
+        .rpc();
+
+      const requestData = await program.account.followRequestAccount.fetch(user3Request);
+      assert.equal(requestData.follower.toString(), user3Profile.toString());
+
+      const followersBefore = (await program.account.profileAccount.fetch(user2Profile))
+        .followerCount.toNumber();
+
+      await program.methods
+        .approveFollow()
+        .accountsPartial({
+          followedProfile: user2Profile,
+          followerProfile: user3Profile,
+          followRequest: user3Request,
+          follow: approvedFollow,
+          owner: user2.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user2])
+        .rpc();
+
+      const followData = await program.account.followAccount.fetch(approvedFollow);
+      assert.equal(followData.follower.toString(), user3Profile.toString());
+
+      const profile = await program.account.profileAccount.fetch(user2Profile);
+      assert.equal(profile.followerCount.toNumber(), followersBefore + 1);
+
+      const requestAccount = await provider.connection.getAccountInfo(user3Request);
+      assert.isNull(requestAccount);
+    });
+
+    it("User2 rejects User1's request", async () => {
+      [user1Request] = PublicKey.findProgramAddressSync(
+        [Buffer.from("follow_request"), user1Profile.toBuffer(), user2Profile.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .requestFollow()
+        .accountsPartial({
+          followerProfile: user1Profile,
+          followedProfile: user2Profile,
+          followRequest: user1Request,
+          owner: user1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+
+      await program.methods
+        .rejectFollow()
+        .accountsPartial({
+          followedProfile: user2Profile,
+          followerProfile: user1Profile,
+          followRequest: user1Request,
+          requester: user1.publicKey,
+          owner: user2.publicKey,
+        })
+        .signers([user2])
+        .rpc();
+
+      const requestAccount = await provider.connection.getAccountInfo(user1Request);
+      assert.isNull(requestAccount);
+    });
+
+    it("Blocking withdraws a pending request", async () => {
+      const [user2Block] = PublicKey.findProgramAddressSync(
+        [Buffer.from("block"), user2Profile.toBuffer(), user1Profile.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .requestFollow()
+        .accountsPartial({
+          followerProfile: user1Profile,
+          followedProfile: user2Profile,
+          followRequest: user1Request,
+          owner: user1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+
+      await program.methods
+        .blockUser()
+        .accountsPartial({
+          blockerProfile: user2Profile,
+          blockedProfile: user1Profile,
+          block: user2Block,
+          blockedOwner: user1.publicKey,
+          owner: user2.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user2])
+        .rpc();
+
+      const requestAccount = await provider.connection.getAccountInfo(user1Request);
+      assert.isNull(requestAccount);
+
+      await program.methods
+        .unblockUser()
+        .accountsPartial({
+          blockerProfile: user2Profile,
+          blockedProfile: user1Profile,
+          block: user2Block,
+          owner: user2.publicKey,
+        })
+        .signers([user2])
+        .rpc();
+    });
+  });
+
+  describe("Poll System", () => {
+    it("Creates a poll", async () => {
+      [poll] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("poll"),
+          community.toBuffer(),
+          new BN(0).toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      const endTime = new BN(Math.floor(Date.now() / 1000) + 86400); // 24 hours from now
+      const options = [user1Profile, user2Profile, user3Profile];
+
+      await program.methods
+        .createPoll(
+          "https://example.com/poll1.json",
+          { profile: {} },
+          options,
+          [],
+          pollConfig(),
+          endTime
+        )
+        .accountsPartial({
+          community: community,
+          membership: membership1,
+          poll: poll,
+          creator: user1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(
+          options.map((pubkey) => ({ pubkey, isSigner: false, isWritable: false }))
+        )
+        .signers([user1])
+        .rpc();
+
+      const pollData = await program.account.pollAccount.fetch(poll);
+      assert.equal(pollData.questionUri, "https://example.com/poll1.json");
+      assert.equal(pollData.optionProfiles.length, 3);
+      assert.equal(pollData.votesPerOption.length, 3);
+      assert.equal(pollData.votesPerOption[0], 0);
+    });
+
+    it("User2 votes on poll", async () => {
+      [vote] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vote"), poll.toBuffer(), user2.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .votePoll(1) // Vote for option 1 (user2Profile)
+        .accountsPartial({
+          poll: poll,
+          membership: membership2,
+          vote: vote,
+          voter: user2.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user2])
+        .rpc();
+
+      const pollData = await program.account.pollAccount.fetch(poll);
+      assert.equal(pollData.votesPerOption[1], 1);
+
+      const voteData = await program.account.voteAccount.fetch(vote);
+      assert.equal(voteData.poll.toString(), poll.toString());
+      assert.equal(voteData.voter.toString(), user2.publicKey.toString());
+      assert.equal(voteData.optionIndex, 1);
+    });
+
+    it("Fails to vote twice", async () => {
+      try {
+        await program.methods
+          .votePoll(2)
+          .accountsPartial({
+            poll: poll,
+            membership: membership2,
+            vote: vote,
+            voter: user2.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user2])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("already in use"));
+      }
+    });
+
+    it("Fails to vote with invalid option", async () => {
+      const [user3Vote] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vote"), poll.toBuffer(), user1.publicKey.toBuffer()],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .votePoll(10) // Invalid option
+          .accountsPartial({
+            poll: poll,
+            membership: membership1,
+            vote: user3Vote,
+            voter: user1.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user1])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("InvalidPollOption"));
+      }
+    });
+  });
+
+  describe("Text Polls", () => {
+    let textPoll: PublicKey;
+
+    it("Creates a poll with text answers", async () => {
+      const communityData = await program.account.communityAccount.fetch(community);
+      [textPoll] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("poll"),
+          community.toBuffer(),
+          communityData.pollCounter.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      const endTime = new BN(Math.floor(Date.now() / 1000) + 86400);
+
+      await program.methods
+        .createPoll(
+          "https://example.com/poll-text.json",
+          { text: {} },
+          [],
+          ["Tabs", "Spaces", "Both"],
+          pollConfig(),
+          endTime
+        )
+        .accountsPartial({
+          community: community,
+          membership: membership1,
+          poll: textPoll,
+          creator: user1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+
+      const pollData = await program.account.pollAccount.fetch(textPoll);
+      assert.ok(pollData.optionKind.text !== undefined);
+      assert.deepEqual(pollData.optionLabels, ["Tabs", "Spaces", "Both"]);
+      assert.equal(pollData.optionProfiles.length, 0);
+      assert.equal(pollData.votesPerOption.length, 3);
+    });
+
+    it("Votes on a text poll like any other poll", async () => {
+      const [textVote] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vote"), textPoll.toBuffer(), user2.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .votePoll(2)
+        .accountsPartial({
+          poll: textPoll,
+          membership: membership2,
+          vote: textVote,
+          voter: user2.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user2])
+        .rpc();
+
+      const pollData = await program.account.pollAccount.fetch(textPoll);
+      assert.equal(pollData.votesPerOption[2], 1);
+    });
+
+    it("Fails to mix profiles into a text poll", async () => {
+      const communityData = await program.account.communityAccount.fetch(community);
+      const [badPoll] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("poll"),
+          community.toBuffer(),
+          communityData.pollCounter.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .createPoll(
+            "https://example.com/poll-bad.json",
+            { text: {} },
+            [user1Profile, user2Profile],
+            ["Yes", "No"],
+            pollConfig(),
+            new BN(Math.floor(Date.now() / 1000) + 86400)
+          )
+          .accountsPartial({
+            community: community,
+            membership: membership1,
+            poll: badPoll,
+            creator: user1.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user1])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("PollOptionKindMismatch"));
+      }
+    });
+  });
+
+  describe("Poll Option Validation", () => {
+    let nextPoll: PublicKey;
+
+    const createProfilePoll = (options: PublicKey[], remaining: PublicKey[]) =>
+      program.methods
+        .createPoll(
+          "https://example.com/poll-validation.json",
+          { profile: {} },
+          options,
+          [],
+          pollConfig(),
+          new BN(Math.floor(Date.now() / 1000) + 86400)
+        )
+        .accountsPartial({
+          community: community,
+          membership: membership1,
+          poll: nextPoll,
+          creator: user1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(
+          remaining.map((pubkey) => ({ pubkey, isSigner: false, isWritable: false }))
+        )
+        .signers([user1])
+        .rpc();
+
+    beforeEach(async () => {
+      const communityData = await program.account.communityAccount.fetch(community);
+      [nextPoll] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("poll"),
+          community.toBuffer(),
+          communityData.pollCounter.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+    });
+
+    it("Fails with a single option", async () => {
+      try {
+        await createProfilePoll([user1Profile], [user1Profile]);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("TooFewPollOptions"));
+      }
+    });
+
+    it("Fails with duplicate options", async () => {
+      try {
+        await createProfilePoll(
+          [user1Profile, user1Profile],
+          [user1Profile, user1Profile]
+        );
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("DuplicatePollOption"));
+      }
+    });
+
+    it("Fails when an option is not a profile", async () => {
+      try {
+        await createProfilePoll([user1Profile, community], [user1Profile, community]);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("InvalidOptionProfile"));
+      }
+    });
+
+    it("Fails when a verified option is not a community member", async () => {
+      const [membership3] = PublicKey.findProgramAddressSync(
+        [Buffer.from("membership"), community.toBuffer(), user3.publicKey.toBuffer()],
+        program.programId
+      );
+
+      try {
+        await createProfilePoll(
+          [user1Profile, user3Profile],
+          [user1Profile, membership1, user3Profile, membership3]
+        );
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("NotCommunityMember"));
+      }
+    });
+
+    it("Creates a poll whose options are verified members", async () => {
+      await createProfilePoll(
+        [user1Profile, user2Profile],
+        [user1Profile, membership1, user2Profile, membership2]
+      );
+
+      const pollData = await program.account.pollAccount.fetch(nextPoll);
+      assert.equal(pollData.optionProfiles.length, 2);
+    });
+  });
+
+  describe("Multi-choice and Approval Polls", () => {
+    let multiPoll: PublicKey;
+
+    const nextPollAddress = async () => {
+      const communityData = await program.account.communityAccount.fetch(community);
+      return PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("poll"),
+          community.toBuffer(),
+          communityData.pollCounter.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      )[0];
+    };
+
+    const createTextPoll = (pollAddress: PublicKey, config: ReturnType<typeof pollConfig>) =>
+      program.methods
+        .createPoll(
+          "https://example.com/poll-multi.json",
+          { text: {} },
+          [],
+          ["Rust", "TypeScript", "Go", "Zig"],
+          config,
+          new BN(Math.floor(Date.now() / 1000) + 86400)
+        )
+        .accountsPartial({
+          community: community,
+          membership: membership1,
+          poll: pollAddress,
+          creator: user1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+
+    const voteMulti = (pollAddress: PublicKey, voter: Keypair, voterMembership: PublicKey, indices: number[]) => {
+      const [vote] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vote"), pollAddress.toBuffer(), voter.publicKey.toBuffer()],
+        program.programId
+      );
+      return program.methods
+        .votePollMulti(Buffer.from(indices))
+        .accountsPartial({
+          poll: pollAddress,
+          membership: voterMembership,
+          vote,
+          voter: voter.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([voter])
+        .rpc()
+        .then(() => vote);
+    };
+
+    it("Fails to create a multi-choice poll with invalid max selections", async () => {
+      try {
+        await createTextPoll(
+          await nextPollAddress(),
+          pollConfig({ mode: { multiChoice: {} }, maxSelections: 5 })
+        );
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("InvalidMaxSelections"));
+      }
+    });
+
+    it("Creates a multi-choice poll", async () => {
+      multiPoll = await nextPollAddress();
+      await createTextPoll(multiPoll, pollConfig({ mode: { multiChoice: {} }, maxSelections: 2 }));
+
+      const pollData = await program.account.pollAccount.fetch(multiPoll);
+      assert.ok(pollData.mode.multiChoice !== undefined);
+      assert.equal(pollData.maxSelections, 2);
+    });
+
+    it("Fails to select more options than allowed", async () => {
+      try {
+        await voteMulti(multiPoll, user2, membership2, [0, 1, 2]);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("TooManySelections"));
+      }
+    });
+
+    it("Fails to select the same option twice", async () => {
+      try {
+        await voteMulti(multiPoll, user2, membership2, [1, 1]);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("DuplicatePollOption"));
+      }
+    });
+
+    it("Counts every selected option", async () => {
+      const vote = await voteMulti(multiPoll, user2, membership2, [0, 3]);
+
+      const voteData = await program.account.voteAccount.fetch(vote);
+      assert.equal(voteData.selections, 0b1001);
+
+      const pollData = await program.account.pollAccount.fetch(multiPoll);
+      assert.equal(pollData.votesPerOption[0], 1);
+      assert.equal(pollData.votesPerOption[1], 0);
+      assert.equal(pollData.votesPerOption[3], 1);
+      assert.equal(pollData.totalVoters, 1);
+    });
+
+    it("Accepts any number of options on an approval poll", async () => {
+      const approvalPoll = await nextPollAddress();
+      await createTextPoll(approvalPoll, pollConfig({ mode: { approval: {} } }));
+
+      await voteMulti(approvalPoll, user1, membership1, [0, 1, 2, 3]);
+
+      const pollData = await program.account.pollAccount.fetch(approvalPoll);
+      assert.equal(pollData.maxSelections, 4);
+      assert.deepEqual(pollData.votesPerOption, [1, 1, 1, 1]);
+      assert.equal(pollData.totalVoters, 1);
+    });
+  });
+
+  describe("Ranked-choice Polls", () => {
+    let rankedPoll: PublicKey;
+    let pollResult: PublicKey;
+    let rankedVote1: PublicKey;
+    let rankedVote2: PublicKey;
+
+    const voteRanked = (voter: Keypair, voterMembership: PublicKey, ranking: number[]) => {
+      const [vote] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vote"), rankedPoll.toBuffer(), voter.publicKey.toBuffer()],
+        program.programId
+      );
+      return program.methods
+        .votePollRanked(Buffer.from(ranking))
+        .accountsPartial({
+          poll: rankedPoll,
+          membership: voterMembership,
+          vote,
+          voter: voter.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([voter])
+        .rpc()
+        .then(() => vote);
+    };
+
+    const tally = (votes: PublicKey[]) =>
+      program.methods
+        .tallyPoll()
+        .accountsPartial({
+          poll: rankedPoll,
+          pollResult,
+          payer: user3.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(
+          votes.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true }))
+        )
+        .signers([user3])
+        .rpc();
+
+    it("Creates a ranked-choice poll", async () => {
+      const communityData = await program.account.communityAccount.fetch(community);
+      [rankedPoll] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("poll"),
+          community.toBuffer(),
+          communityData.pollCounter.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      [pollResult] = PublicKey.findProgramAddressSync(
+        [Buffer.from("poll_result"), rankedPoll.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .createPoll(
+          "https://example.com/poll-ranked.json",
+          { text: {} },
+          [],
+          ["Alice", "Bob", "Carol"],
+          pollConfig({ mode: { rankedChoice: {} } }),
+          new BN(Math.floor(Date.now() / 1000) + 5)
+        )
+        .accountsPartial({
+          community: community,
+          membership: membership1,
+          poll: rankedPoll,
+          creator: user1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+
+      const pollData = await program.account.pollAccount.fetch(rankedPoll);
+      assert.ok(pollData.mode.rankedChoice !== undefined);
+    });
+
+    it("Fails to cast a plain vote on a ranked-choice poll", async () => {
+      const [vote] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vote"), rankedPoll.toBuffer(), user1.publicKey.toBuffer()],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .votePoll(0)
+          .accountsPartial({
+            poll: rankedPoll,
+            membership: membership1,
+            vote,
+            voter: user1.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user1])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("PollModeMismatch"));
+      }
+    });
+
+    it("Records ranked ballots", async () => {
+      rankedVote1 = await voteRanked(user1, membership1, [2, 0]);
+      rankedVote2 = await voteRanked(user2, membership2, [1, 0]);
+
+      const voteData = await program.account.voteAccount.fetch(rankedVote1);
+      assert.deepEqual([...voteData.ranking], [2, 0]);
+
+      const pollData = await program.account.pollAccount.fetch(rankedPoll);
+      assert.deepEqual(pollData.votesPerOption, [0, 1, 1]);
+      assert.equal(pollData.totalVoters, 2);
+    });
+
+    it("Fails to tally before the poll ends", async () => {
+      try {
+        await tally([rankedVote1, rankedVote2]);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("PollNotEnded"));
+      }
+    });
+
+    it("Tallies in batches until a winner is found", async () => {
+      await sleep(7000);
+
+      // Round 0 spans two transactions
+      await tally([rankedVote1]);
+      let resultData = await program.account.pollResultAccount.fetch(pollResult);
+      assert.equal(resultData.round, 0);
+      assert.equal(resultData.processedVotes, 1);
+
+      // Repeating a counted ballot is skipped; round 0 closes and Alice is eliminated
+      await tally([rankedVote1, rankedVote2]);
+      resultData = await program.account.pollResultAccount.fetch(pollResult);
+      assert.equal(resultData.round, 1);
+      assert.deepEqual(resultData.rounds[0], [0, 1, 1]);
+      assert.equal(resultData.eliminated, 0b001);
+      assert.isNull(resultData.winner);
+
+      // Round 1 ties Bob and Carol; Carol is eliminated and Bob wins
+      await tally([rankedVote1, rankedVote2]);
+      resultData = await program.account.pollResultAccount.fetch(pollResult);
+      assert.deepEqual(resultData.rounds[1], [0, 1, 1]);
+      assert.equal(resultData.winner, 1);
+      assert.isNotNull(resultData.completedAt);
+    });
+
+    it("Fails to tally a completed poll", async () => {
+      try {
+        await tally([rankedVote1]);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("TallyComplete"));
+      }
+    });
+
+    it("Fails to finalize a poll that has not ended", async () => {
+      try {
+        await program.methods
+          .finalizePoll()
+          .accountsPartial({ poll: poll, pollResult: null })
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("PollNotEnded"));
+      }
+    });
+
+    it("Fails to close a vote record before finalization", async () => {
+      try {
+        await program.methods
+          .closeVoteRecord()
+          .accountsPartial({ poll: poll, vote: vote, voter: user2.publicKey })
+          .signers([user2])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("PollNotFinalized"));
+      }
+    });
+
+    it("Finalizes the poll from its tally", async () => {
+      await program.methods
+        .finalizePoll()
+        .accountsPartial({ poll: rankedPoll, pollResult })
+        .rpc();
+
+      const pollData = await program.account.pollAccount.fetch(rankedPoll);
+      assert.isNotNull(pollData.finalizedAt);
+      assert.equal(pollData.winners, 0b010);
+      assert.equal(pollData.openVoteRecords, 2);
+    });
+
+    it("Fails to close the poll while vote records are open", async () => {
+      try {
+        await program.methods
+          .closePoll()
+          .accountsPartial({ poll: rankedPoll, pollResult, creator: user1.publicKey })
+          .signers([user1])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("VoteRecordsOpen"));
+      }
+    });
+
+    it("Voters reclaim rent and the creator closes the poll", async () => {
+      await program.methods
+        .closeVoteRecord()
+        .accountsPartial({ poll: rankedPoll, vote: rankedVote1, voter: user1.publicKey })
+        .signers([user1])
+        .rpc();
+      await program.methods
+        .closeVoteRecord()
+        .accountsPartial({ poll: rankedPoll, vote: rankedVote2, voter: user2.publicKey })
+        .signers([user2])
+        .rpc();
+
+      const pollData = await program.account.pollAccount.fetch(rankedPoll);
+      assert.equal(pollData.openVoteRecords, 0);
+
+      await program.methods
+        .closePoll()
+        .accountsPartial({ poll: rankedPoll, pollResult, creator: user1.publicKey })
+        .signers([user1])
+        .rpc();
+
+      assert.isNull(await provider.connection.getAccountInfo(rankedPoll));
+      assert.isNull(await provider.connection.getAccountInfo(pollResult));
+      assert.isNull(await provider.connection.getAccountInfo(rankedVote1));
+    });
+
+    it("Closes the poll result of a poll that missed quorum", async () => {
+      const communityData = await program.account.communityAccount.fetch(community);
+      const [unvotedPoll] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("poll"),
+          community.toBuffer(),
+          communityData.pollCounter.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      const [unvotedResult] = PublicKey.findProgramAddressSync(
+        [Buffer.from("poll_result"), unvotedPoll.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .createPoll(
+          "https://example.com/poll-ranked-unvoted.json",
+          { text: {} },
+          [],
+          ["Alice", "Bob", "Carol"],
+          pollConfig({ mode: { rankedChoice: {} }, quorum: 1 }),
+          new BN(Math.floor(Date.now() / 1000) + 5)
+        )
+        .accountsPartial({
+          community: community,
+          membership: membership1,
+          poll: unvotedPoll,
+          creator: user1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+
+      await sleep(7000);
+      await program.methods
+        .tallyPoll()
+        .accountsPartial({
+          poll: unvotedPoll,
+          pollResult: unvotedResult,
+          payer: user3.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user3])
+        .rpc();
+      await program.methods
+        .finalizePoll()
+        .accountsPartial({ poll: unvotedPoll, pollResult: null })
+        .rpc();
+
+      try {
+        await program.methods
+          .closePoll()
+          .accountsPartial({ poll: unvotedPoll, pollResult: null, creator: user1.publicKey })
+          .signers([user1])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("PollResultRequired"));
+      }
+
+      await program.methods
+        .closePoll()
+        .accountsPartial({ poll: unvotedPoll, pollResult: unvotedResult, creator: user1.publicKey })
+        .signers([user1])
+        .rpc();
+
+      assert.isNull(await provider.connection.getAccountInfo(unvotedPoll));
+      assert.isNull(await provider.connection.getAccountInfo(unvotedResult));
+    });
+  });
+
+  describe("Changing and Revoking Votes", () => {
+    let changePoll: PublicKey;
+    let changeVote: PublicKey;
+
+    it("Creates a poll that allows vote changes", async () => {
+      const communityData = await program.account.communityAccount.fetch(community);
+      [changePoll] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("poll"),
+          community.toBuffer(),
+          communityData.pollCounter.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      [changeVote] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vote"), changePoll.toBuffer(), user2.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .createPoll(
+          "https://example.com/poll-change.json",
+          { text: {} },
+          [],
+          ["Morning", "Evening"],
+          pollConfig({ allowVoteChange: true }),
+          new BN(Math.floor(Date.now() / 1000) + 86400)
+        )
+        .accountsPartial({
+          community: community,
+          membership: membership1,
+          poll: changePoll,
+          creator: user1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+
+      await program.methods
+        .votePoll(0)
+        .accountsPartial({
+          poll: changePoll,
+          membership: membership2,
+          vote: changeVote,
+          voter: user2.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user2])
+        .rpc();
+    });
+
+    it("Moves the vote to a new option", async () => {
+      await program.methods
+        .changeVote(Buffer.from([1]))
+        .accountsPartial({
+          poll: changePoll,
+          vote: changeVote,
+          voter: user2.publicKey,
+        })
+        .signers([user2])
+        .rpc();
+
+      const pollData = await program.account.pollAccount.fetch(changePoll);
+      assert.deepEqual(pollData.votesPerOption, [0, 1]);
+      assert.equal(pollData.totalVoters, 1);
+
+      const voteData = await program.account.voteAccount.fetch(changeVote);
+      assert.equal(voteData.optionIndex, 1);
+    });
+
+    it("Revokes the vote and refunds rent", async () => {
+      await program.methods
+        .revokeVote()
+        .accountsPartial({
+          poll: changePoll,
+          vote: changeVote,
+          voter: user2.publicKey,
+        })
+        .signers([user2])
+        .rpc();
+
+      const pollData = await program.account.pollAccount.fetch(changePoll);
+      assert.deepEqual(pollData.votesPerOption, [0, 0]);
+      assert.equal(pollData.totalVoters, 0);
+
+      const voteInfo = await provider.connection.getAccountInfo(changeVote);
+      assert.isNull(voteInfo);
+    });
+
+    it("Fails to change a vote when the poll does not allow it", async () => {
+      try {
+        await program.methods
+          .changeVote(Buffer.from([0]))
+          .accountsPartial({
+            poll: poll,
+            vote: vote,
+            voter: user2.publicKey,
+          })
+          .signers([user2])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("VoteChangeNotAllowed"));
+      }
+    });
+  });
+
+  describe("Legacy Polls", () => {
+    // Loaded at genesis in the layout polls had before poll modes (see Anchor.toml)
+    const legacyPoll = new PublicKey("HKV5hPyMTsmpjcpShmobmHxtsnq9X8wBwKJqMHFcfeg4");
+    const legacyVote = new PublicKey("2t8DJRdACXFHLWXDMvhK2TzSq5PWtL8U44QmqHRzZT9H");
+    const legacyVoter = Keypair.fromSecretKey(
+      Uint8Array.from(require("./fixtures/legacy_voter.json"))
+    );
+
+    it("Fails to read legacy accounts before migration", async () => {
+      try {
+        await program.account.voteAccount.fetch(legacyVote);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.notInclude(err.toString(), "Should have thrown error");
+      }
+    });
+
+    it("Migrates a legacy poll", async () => {
+      await program.methods
+        .migratePoll()
+        .accountsPartial({
+          poll: legacyPoll,
+          payer: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const pollData = await program.account.pollAccount.fetch(legacyPoll);
+      assert.deepEqual(pollData.mode, { singleChoice: {} });
+      assert.equal(pollData.maxSelections, 1);
+      assert.equal(pollData.totalVoters, 1);
+      assert.equal(pollData.optionProfiles.length, 10);
+      assert.equal(pollData.votesPerOption[0], 1);
+    });
+
+    it("Migrates a legacy vote record", async () => {
+      await program.methods
+        .migrateVote()
+        .accountsPartial({
+          vote: legacyVote,
+          payer: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const voteData = await program.account.voteAccount.fetch(legacyVote);
+      assert.ok(voteData.poll.equals(legacyPoll));
+      assert.ok(voteData.voter.equals(legacyVoter.publicKey));
+      assert.equal(voteData.selections, 1);
+      assert.equal(voteData.weight.toNumber(), 1);
+    });
+
+    it("Fails to migrate an account twice", async () => {
+      try {
+        await program.methods
+          .migrateVote()
+          .accountsPartial({
+            vote: legacyVote,
+            payer: provider.wallet.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("AccountAlreadyMigrated"));
+      }
+    });
+
+    it("Finalizes the migrated poll and closes the vote record", async () => {
+      await program.methods
+        .finalizePoll()
+        .accountsPartial({ poll: legacyPoll, pollResult: null })
+        .rpc();
+
+      const pollData = await program.account.pollAccount.fetch(legacyPoll);
+      assert.equal(pollData.winners, 0b1);
+
+      await program.methods
+        .closeVoteRecord()
+        .accountsPartial({
+          poll: legacyPoll,
+          vote: legacyVote,
+          voter: legacyVoter.publicKey,
+        })
+        .signers([legacyVoter])
+        .rpc();
+
+      const voteInfo = await provider.connection.getAccountInfo(legacyVote);
+      assert.isNull(voteInfo);
+    });
+  });
+
+  describe("Token-weighted Polls", () => {
+    let weightedPoll: PublicKey;
+    let weightMint: PublicKey;
+    let voterTokens: PublicKey;
+    let weightedVote: PublicKey;
+    let voteEscrow: PublicKey;
+
+    before(async () => {
+      weightMint = await createMint(provider.connection, user1, user1.publicKey, null, 0);
+      voterTokens = await createAccount(provider.connection, user2, weightMint, user2.publicKey);
+      await mintTo(provider.connection, user1, weightMint, voterTokens, user1, 1000);
+    });
+
+    it("Creates a token-weighted poll", async () => {
+      const communityData = await program.account.communityAccount.fetch(community);
+      [weightedPoll] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("poll"),
+          community.toBuffer(),
+          communityData.pollCounter.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      [weightedVote] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vote"), weightedPoll.toBuffer(), user2.publicKey.toBuffer()],
+        program.programId
+      );
+      [voteEscrow] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vote_escrow"), weightedPoll.toBuffer(), user2.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .createPoll(
+          "https://example.com/poll-weighted.json",
+          { text: {} },
+          [],
+          ["Raise fees", "Keep fees"],
+          pollConfig({ weightMint }),
+          new BN(Math.floor(Date.now() / 1000) + 5)
+        )
+        .accountsPartial({
+          community: community,
+          membership: membership1,
+          poll: weightedPoll,
+          creator: user1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+
+      const pollData = await program.account.pollAccount.fetch(weightedPoll);
+      assert.ok(pollData.weightMint.equals(weightMint));
+    });
+
+    it("Fails to cast an unweighted vote", async () => {
+      const [vote] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vote"), weightedPoll.toBuffer(), user1.publicKey.toBuffer()],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .votePoll(0)
+          .accountsPartial({
+            poll: weightedPoll,
+            membership: membership1,
+            vote,
+            voter: user1.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user1])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("PollModeMismatch"));
+      }
+    });
+
+    it("Counts the escrowed token balance as vote weight", async () => {
+      await program.methods
+        .votePollWeighted(Buffer.from([1]), new BN(400))
+        .accountsPartial({
+          poll: weightedPoll,
+          membership: membership2,
+          vote: weightedVote,
+          weightMint,
+          voterTokenAccount: voterTokens,
+          voteEscrow,
+          voter: user2.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user2])
+        .rpc();
+
+      const pollData = await program.account.pollAccount.fetch(weightedPoll);
+      assert.equal(pollData.votesPerOption[1], 1);
+      assert.equal(pollData.weightedVotes[1].toNumber(), 400);
+      assert.equal(pollData.totalVoters, 1);
+
+      const escrow = await getAccount(provider.connection, voteEscrow);
+      assert.equal(Number(escrow.amount), 400);
+    });
+
+    const withdraw = () =>
+      program.methods
+        .withdrawVoteTokens()
+        .accountsPartial({
+          poll: weightedPoll,
+          vote: weightedVote,
+          voteEscrow,
+          voterTokenAccount: voterTokens,
+          voter: user2.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([user2])
+        .rpc();
+
+    it("Fails to withdraw vote tokens while the poll is open", async () => {
+      try {
+        await withdraw();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("VoteTokensLocked"));
+      }
+    });
+
+    it("Returns vote tokens after the poll is closed", async () => {
+      await sleep(7000);
+      await program.methods
+        .finalizePoll()
+        .accountsPartial({ poll: weightedPoll, pollResult: null })
+        .rpc();
+      await program.methods
+        .closeVoteRecord()
+        .accountsPartial({ poll: weightedPoll, vote: weightedVote, voter: user2.publicKey })
+        .signers([user2])
+        .rpc();
+      await program.methods
+        .closePoll()
+        .accountsPartial({ poll: weightedPoll, pollResult: null, creator: user1.publicKey })
+        .signers([user1])
+        .rpc();
+      assert.isNull(await provider.connection.getAccountInfo(weightedPoll));
+
+      await withdraw();
+
+      const tokens = await getAccount(provider.connection, voterTokens);
+      assert.equal(Number(tokens.amount), 1000);
+      assert.isNull(await provider.connection.getAccountInfo(voteEscrow));
+    });
+  });
+
+  describe("Secret Ballot Polls", () => {
+    let secretPoll: PublicKey;
+    let secretVote1: PublicKey;
+    let secretVote2: PublicKey;
+    const salt = randomBytes(32);
+
+    const commitmentFor = (voter: PublicKey, options: number[], voteSalt: Buffer) =>
+      createHash("sha256")
+        .update(Buffer.concat([secretPoll.toBuffer(), voter.toBuffer(), Buffer.from(options), voteSalt]))
+        .digest();
+
+    const reveal = (options: number[], voteSalt: Buffer) =>
+      program.methods
+        .revealVote(Buffer.from(options), [...voteSalt])
+        .accountsPartial({ poll: secretPoll, vote: secretVote2, voter: user2.publicKey })
+        .signers([user2])
+        .rpc();
+
+    it("Creates a secret ballot poll", async () => {
+      const communityData = await program.account.communityAccount.fetch(community);
+      [secretPoll] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("poll"),
+          community.toBuffer(),
+          communityData.pollCounter.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      [secretVote1] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vote"), secretPoll.toBuffer(), user1.publicKey.toBuffer()],
+        program.programId
+      );
+      [secretVote2] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vote"), secretPoll.toBuffer(), user2.publicKey.toBuffer()],
+        program.programId
+      );
+
+      const now = Math.floor(Date.now() / 1000);
+      await program.methods
+        .createPoll(
+          "https://example.com/poll-secret.json",
+          { text: {} },
+          [],
+          ["Yes", "No"],
+          pollConfig({ revealEndTime: new BN(now + 3600) }),
+          new BN(now + 5)
+        )
+        .accountsPartial({
+          community: community,
+          membership: membership1,
+          poll: secretPoll,
+          creator: user1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+    });
+
+    it("Fails to cast an open vote on a secret ballot poll", async () => {
+      try {
+        await program.methods
+          .votePoll(0)
+          .accountsPartial({
+            poll: secretPoll,
+            membership: membership2,
+            vote: secretVote2,
+            voter: user2.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user2])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("PollModeMismatch"));
+      }
+    });
+
+    it("Commits hidden votes without counting them", async () => {
+      await program.methods
+        .commitVote([...commitmentFor(user2.publicKey, [1], salt)])
+        .accountsPartial({
+          poll: secretPoll,
+          membership: membership2,
+          vote: secretVote2,
+          voter: user2.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user2])
+        .rpc();
+
+      // User1 commits and never reveals
+      await program.methods
+        .commitVote([...commitmentFor(user1.publicKey, [0], randomBytes(32))])
+        .accountsPartial({
+          poll: secretPoll,
+          membership: membership1,
+          vote: secretVote1,
+          voter: user1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+
+      const pollData = await program.account.pollAccount.fetch(secretPoll);
+      assert.deepEqual(pollData.votesPerOption, [0, 0]);
+      assert.equal(pollData.unrevealedVotes, 2);
+    });
+
+    it("Fails to reveal before the poll ends", async () => {
+      try {
+        await reveal([1], salt);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("NotInRevealWindow"));
+      }
+    });
+
+    it("Fails to reveal a different choice", async () => {
+      await sleep(7000);
+
+      try {
+        await reveal([0], salt);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("CommitmentMismatch"));
+      }
+    });
+
+    it("Counts the vote once revealed", async () => {
+      await reveal([1], salt);
+
+      const pollData = await program.account.pollAccount.fetch(secretPoll);
+      assert.deepEqual(pollData.votesPerOption, [0, 1]);
+      assert.equal(pollData.totalVoters, 1);
+      assert.equal(pollData.unrevealedVotes, 1);
+
+      const voteData = await program.account.voteAccount.fetch(secretVote2);
+      assert.isNull(voteData.commitment);
+      assert.equal(voteData.optionIndex, 1);
+    });
+  });
+
+  describe("Poll Scheduling and Quorum", () => {
+    const createScheduledPoll = async (config: ReturnType<typeof pollConfig>, endTime: BN) => {
+      const communityData = await program.account.communityAccount.fetch(community);
+      const [scheduledPoll] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("poll"),
+          community.toBuffer(),
+          communityData.pollCounter.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .createPoll(
+          "https://example.com/poll-scheduled.json",
+          { text: {} },
+          [],
+          ["Yes", "No"],
+          config,
+          endTime
+        )
+        .accountsPartial({
+          community: community,
+          membership: membership1,
+          poll: scheduledPoll,
+          creator: user1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+
+      return scheduledPoll;
+    };
+
+    const voteAsUser2 = (scheduledPoll: PublicKey) => {
+      const [vote] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vote"), scheduledPoll.toBuffer(), user2.publicKey.toBuffer()],
+        program.programId
+      );
+      return program.methods
+        .votePoll(0)
+        .accountsPartial({
+          poll: scheduledPoll,
+          membership: membership2,
+          vote,
+          voter: user2.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user2])
+        .rpc();
+    };
+
+    it("Fails to vote before the poll starts", async () => {
+      const now = Math.floor(Date.now() / 1000);
+      const scheduledPoll = await createScheduledPoll(
+        pollConfig({ startTime: new BN(now + 3600) }),
+        new BN(now + 7200)
+      );
+
+      const pollData = await program.account.pollAccount.fetch(scheduledPoll);
+      assert.equal(pollData.startTime.toNumber(), now + 3600);
+
+      try {
+        await voteAsUser2(scheduledPoll);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("PollNotStarted"));
+      }
+    });
+
+    it("Fails to vote with a membership younger than the minimum age", async () => {
+      const scheduledPoll = await createScheduledPoll(
+        pollConfig({ minMembershipAge: new BN(30 * 24 * 60 * 60) }),
+        new BN(Math.floor(Date.now() / 1000) + 86400)
+      );
+
+      try {
+        await voteAsUser2(scheduledPoll);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("MembershipTooNew"));
+      }
+    });
+
+    it("Finalizes without winners when quorum is not met", async () => {
+      const scheduledPoll = await createScheduledPoll(
+        pollConfig({ quorum: 2 }),
+        new BN(Math.floor(Date.now() / 1000) + 5)
+      );
+      await voteAsUser2(scheduledPoll);
+      await sleep(7000);
+
+      await program.methods
+        .finalizePoll()
+        .accountsPartial({ poll: scheduledPoll, pollResult: null })
+        .rpc();
+
+      const pollData = await program.account.pollAccount.fetch(scheduledPoll);
+      assert.isFalse(pollData.quorumMet);
+      assert.equal(pollData.winners, 0);
+      assert.equal(pollData.votesPerOption[0], 1);
+    });
+  });
+
+  describe("Moderator Elections", () => {
+    let electionPoll: PublicKey;
+    let electedModerator: PublicKey;
+
+    const seat = (winnerProfile: PublicKey, winner: PublicKey, poll = electionPoll) =>
+      program.methods
+        .seatElectedModerator()
+        .accountsPartial({
+          poll,
+          community: community,
+          winnerProfile,
+          membership: PublicKey.findProgramAddressSync(
+            [Buffer.from("membership"), community.toBuffer(), winner.toBuffer()],
+            program.programId
+          )[0],
+          moderator: PublicKey.findProgramAddressSync(
+            [Buffer.from("moderator"), community.toBuffer(), winner.toBuffer()],
+            program.programId
+          )[0],
+          payer: user3.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user3])
+        .rpc();
+
+    it("Fails to create an election with text options", async () => {
+      const communityData = await program.account.communityAccount.fetch(community);
+      const [badPoll] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("poll"),
+          community.toBuffer(),
+          communityData.pollCounter.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .createPoll(
+            "https://example.com/poll-election-bad.json",
+            { text: {} },
+            [],
+            ["Alice", "Bob"],
+            pollConfig({ electionSeats: 1, termLength: new BN(3600) }),
+            new BN(Math.floor(Date.now() / 1000) + 86400)
+          )
+          .accountsPartial({
+            community: community,
+            membership: membership1,
+            poll: badPoll,
+            creator: user1.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user1])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("InvalidElectionConfig"));
+      }
+    });
+
+    it("Fails to open an election before governance is enabled", async () => {
+      const communityData = await program.account.communityAccount.fetch(community);
+      const [badPoll] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("poll"),
+          community.toBuffer(),
+          communityData.pollCounter.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      const candidates = [user1Profile, user2Profile];
+
+      try {
+        await program.methods
+          .createPoll(
+            "https://example.com/poll-election-early.json",
+            { profile: {} },
+            candidates,
+            [],
+            pollConfig({ electionSeats: 1, termLength: new BN(3600), quorum: 2 }),
+            new BN(Math.floor(Date.now() / 1000) + 86400)
+          )
+          .accountsPartial({
+            community: community,
+            membership: membership1,
+            poll: badPoll,
+            creator: user1.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .remainingAccounts(
+            candidates.map((pubkey) => ({ pubkey, isSigner: false, isWritable: false }))
+          )
+          .signers([user1])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("GovernanceDisabled"));
+      }
+    });
+
+    it("Fails to set governance minimums as a non-creator", async () => {
+      try {
+        await program.methods
+          .setGovernanceConfig(2, 5000, new BN(0))
+          .accountsPartial({ community: community, creator: user2.publicKey })
+          .signers([user2])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("NotCommunityCreator"));
+      }
+    });
+
+    it("Creator enables governance", async () => {
+      await program.methods
+        .setGovernanceConfig(2, 5000, new BN(0))
+        .accountsPartial({ community: community, creator: user1.publicKey })
+        .signers([user1])
+        .rpc();
+
+      const communityData = await program.account.communityAccount.fetch(community);
+      assert.equal(communityData.minGovernanceQuorum, 2);
+      assert.equal(communityData.minGovernanceThresholdBps, 5000);
+    });
+
+    // Creates an election between user1 and user2 that user1 wins
+    const runElection = async (uri: string, termLength: number) => {
+      const communityData = await program.account.communityAccount.fetch(community);
+      const [poll] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("poll"),
+          community.toBuffer(),
+          communityData.pollCounter.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      const candidates = [user1Profile, user2Profile];
+      await program.methods
+        .createPoll(
+          uri,
+          { profile: {} },
+          candidates,
+          [],
+          pollConfig({ electionSeats: 1, termLength: new BN(termLength), quorum: 2 }),
+          new BN(Math.floor(Date.now() / 1000) + 5)
+        )
+        .accountsPartial({
+          community: community,
+          membership: membership1,
+          poll,
+          creator: user1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(
+          candidates.map((pubkey) => ({ pubkey, isSigner: false, isWritable: false }))
+        )
+        .signers([user1])
+        .rpc();
+
+      for (const [voter, voterMembership] of [
+        [user1, membership1],
+        [user2, membership2],
+      ] as [Keypair, PublicKey][]) {
+        const [vote] = PublicKey.findProgramAddressSync(
+          [Buffer.from("vote"), poll.toBuffer(), voter.publicKey.toBuffer()],
+          program.programId
+        );
+        await program.methods
+          .votePoll(0)
+          .accountsPartial({
+            poll,
+            membership: voterMembership,
+            vote,
+            voter: voter.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([voter])
+          .rpc();
+      }
+
+      await sleep(7000);
+      await program.methods
+        .finalizePoll()
+        .accountsPartial({ poll, pollResult: null })
+        .rpc();
+
+      return poll;
+    };
+
+    it("Runs an election", async () => {
+      [electedModerator] = PublicKey.findProgramAddressSync(
+        [Buffer.from("moderator"), community.toBuffer(), user1.publicKey.toBuffer()],
+        program.programId
+      );
+      electionPoll = await runElection("https://example.com/poll-election.json", 3600);
+
+      const pollData = await program.account.pollAccount.fetch(electionPoll);
+      assert.equal(pollData.winners, 0b01);
+    });
+
+    it("Fails to seat a losing candidate", async () => {
+      try {
+        await seat(user2Profile, user2.publicKey);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("NotElectionWinner"));
+      }
+    });
+
+    it("Seats the winner for a fixed term", async () => {
+      await seat(user1Profile, user1.publicKey);
+
+      const pollData = await program.account.pollAccount.fetch(electionPoll);
+      const moderatorData = await program.account.moderatorAccount.fetch(electedModerator);
+      assert.ok(moderatorData.user.equals(user1.publicKey));
+      assert.ok(moderatorData.addedBy.equals(electionPoll));
+      assert.equal(moderatorData.termEndsAt.toNumber(), pollData.endTime.toNumber() + 3600);
+
+      assert.equal(pollData.seatedWinners, 0b01);
+    });
+
+    it("Re-election extends the current term", async () => {
+      const reelection = await runElection("https://example.com/poll-reelection.json", 7200);
+      await seat(user1Profile, user1.publicKey, reelection);
+
+      const pollData = await program.account.pollAccount.fetch(reelection);
+      const moderatorData = await program.account.moderatorAccount.fetch(electedModerator);
+      assert.ok(moderatorData.addedBy.equals(electionPoll));
+      assert.equal(moderatorData.termEndsAt.toNumber(), pollData.endTime.toNumber() + 7200);
+    });
+
+    it("Fails to reseat a removed moderator", async () => {
+      await program.methods
+        .removeModerator()
+        .accountsPartial({
+          community: community,
+          moderator: electedModerator,
+          creator: user1.publicKey,
+        })
+        .signers([user1])
+        .rpc();
+
+      try {
+        await seat(user1Profile, user1.publicKey);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("SeatAlreadyClaimed"));
+      }
+    });
+
+    it("Seats a winner whose earlier term has expired", async () => {
+      const shortElection = await runElection("https://example.com/poll-election-short.json", 1);
+      await seat(user1Profile, user1.publicKey, shortElection);
+      await sleep(2000);
+
+      const nextElection = await runElection("https://example.com/poll-election-next.json", 3600);
+      await seat(user1Profile, user1.publicKey, nextElection);
+
+      const pollData = await program.account.pollAccount.fetch(nextElection);
+      const moderatorData = await program.account.moderatorAccount.fetch(electedModerator);
+      assert.ok(moderatorData.addedBy.equals(nextElection));
+      assert.equal(moderatorData.termEndsAt.toNumber(), pollData.endTime.toNumber() + 3600);
+    });
+  });
+
+  describe("Prize Polls", () => {
+    const createProfilePoll = async (
+      uri: string,
+      config,
+      seconds: number,
+      candidates = [user1Profile, user2Profile]
+    ) => {
+      const communityData = await program.account.communityAccount.fetch(community);
+      const [poll] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("poll"),
+          community.toBuffer(),
+          communityData.pollCounter.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      await program.methods
+        .createPoll(
+          uri,
+          { profile: {} },
+          candidates,
+          [],
+          config,
+          new BN(Math.floor(Date.now() / 1000) + seconds)
+        )
+        .accountsPartial({
+          community: community,
+          membership: membership1,
+          poll,
+          creator: user1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(
+          candidates.map((pubkey) => ({ pubkey, isSigner: false, isWritable: false }))
+        )
+        .signers([user1])
+        .rpc();
+      return poll;
+    };
+
+    const prizeFor = (poll: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("prize"), poll.toBuffer()],
+        program.programId
+      )[0];
+
+    const prizeVaultFor = (poll: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("prize_vault"), poll.toBuffer()],
+        program.programId
+      )[0];
+
+    it("Splits a SOL prize between tied winners", async () => {
+      const poll = await createProfilePoll(
+        "https://example.com/poll-prize.json",
+        pollConfig(),
+        5
+      );
+      const prize = prizeFor(poll);
+      const amount = 0.01 * LAMPORTS_PER_SOL;
+
+      await program.methods
+        .fundPollPrize(new BN(amount))
+        .accountsPartial({
+          poll,
+          prize,
+          creator: user1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+
+      const prizeData = await program.account.prizeAccount.fetch(prize);
+      assert.equal(prizeData.amount.toNumber(), amount);
+      assert.isNull(prizeData.mint);
+
+      for (const [voter, voterMembership, option] of [
+        [user1, membership1, 0],
+        [user2, membership2, 1],
+      ] as [Keypair, PublicKey, number][]) {
+        const [vote] = PublicKey.findProgramAddressSync(
+          [Buffer.from("vote"), poll.toBuffer(), voter.publicKey.toBuffer()],
+          program.programId
+        );
+        await program.methods
+          .votePoll(option)
+          .accountsPartial({
+            poll,
+            membership: voterMembership,
+            vote,
+            voter: voter.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([voter])
+          .rpc();
+      }
+
+      await sleep(7000);
+      await program.methods
+        .finalizePoll()
+        .accountsPartial({ poll, pollResult: null })
+        .rpc();
+
+      // The poll cannot be closed while the prize is unsettled
+      try {
+        await program.methods
+          .closePoll()
+          .accountsPartial({ poll, pollResult: null, creator: user1.publicKey })
+          .signers([user1])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("PrizeUnsettled"));
+      }
+
+      const settle = (recipients: PublicKey[]) =>
+        program.methods
+          .settlePrizePoll()
+          .accountsPartial({
+            poll,
+            prize,
+            funder: user1.publicKey,
+            prizeVault: null,
+            funderTokenAccount: null,
+            tokenProgram: null,
+          })
+          .remainingAccounts(
+            [user1Profile, user2Profile].flatMap((profile, i) => [
+              { pubkey: profile, isSigner: false, isWritable: false },
+              { pubkey: recipients[i], isSigner: false, isWritable: true },
+            ])
+          )
+          .rpc();
+
+      try {
+        await settle([user1.publicKey, user3.publicKey]);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("PrizeAccountsMismatch"));
+      }
+
+      const before = await provider.connection.getBalance(user2.publicKey);
+      await settle([user1.publicKey, user2.publicKey]);
+      const after = await provider.connection.getBalance(user2.publicKey);
+
+      assert.equal(after - before, amount / 2);
+      assert.isNull(await provider.connection.getAccountInfo(prize));
+      const pollData = await program.account.pollAccount.fetch(poll);
+      assert.isFalse(pollData.hasPrize);
+    });
+
+    it("Fails to fund a prize after voting starts", async () => {
+      const poll = await createProfilePoll(
+        "https://example.com/poll-prize-late.json",
+        pollConfig(),
+        3600
+      );
+      const [vote] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vote"), poll.toBuffer(), user2.publicKey.toBuffer()],
+        program.programId
+      );
+      await program.methods
+        .votePoll(0)
+        .accountsPartial({
+          poll,
+          membership: membership2,
+          vote,
+          voter: user2.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user2])
+        .rpc();
+
+      try {
+        await program.methods
+          .fundPollPrize(new BN(LAMPORTS_PER_SOL / 100))
+          .accountsPartial({
+            poll,
+            prize: prizeFor(poll),
+            creator: user1.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user1])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("InvalidPrizePoll"));
+      }
+    });
+
+    it("Refunds a token prize when quorum is not met", async () => {
+      const poll = await createProfilePoll(
+        "https://example.com/poll-prize-token.json",
+        pollConfig({ quorum: 5 }),
+        5
+      );
+      const prize = prizeFor(poll);
+      const prizeVault = prizeVaultFor(poll);
+      const prizeMint = await createMint(provider.connection, user1, user1.publicKey, null, 0);
+      const creatorTokens = await createAccount(
+        provider.connection,
+        user1,
+        prizeMint,
+        user1.publicKey
+      );
+      await mintTo(provider.connection, user1, prizeMint, creatorTokens, user1, 500);
+
+      await program.methods
+        .fundPollPrizeToken(new BN(500))
+        .accountsPartial({
+          poll,
+          prize,
+          prizeMint,
+          creatorTokenAccount: creatorTokens,
+          prizeVault,
+          creator: user1.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+
+      const vault = await getAccount(provider.connection, prizeVault);
+      assert.equal(Number(vault.amount), 500);
+
+      await sleep(7000);
+      await program.methods
+        .finalizePoll()
+        .accountsPartial({ poll, pollResult: null })
+        .rpc();
+
+      await program.methods
+        .settlePrizePoll()
+        .accountsPartial({
+          poll,
+          prize,
+          funder: user1.publicKey,
+          prizeVault,
+          funderTokenAccount: creatorTokens,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+
+      const refunded = await getAccount(provider.connection, creatorTokens);
+      assert.equal(Number(refunded.amount), 500);
+      assert.isNull(await provider.connection.getAccountInfo(prizeVault));
+      assert.isNull(await provider.connection.getAccountInfo(prize));
+    });
+
+    it("Refunds the share of a winner without a token account", async () => {
+      const poll = await createProfilePoll(
+        "https://example.com/poll-prize-no-ata.json",
+        pollConfig(),
+        5
+      );
+      const prize = prizeFor(poll);
+      const prizeVault = prizeVaultFor(poll);
+      const prizeMint = await createMint(provider.connection, user1, user1.publicKey, null, 0);
+      const creatorTokens = await createAccount(
+        provider.connection,
+        user1,
+        prizeMint,
+        user1.publicKey
+      );
+      await mintTo(provider.connection, user1, prizeMint, creatorTokens, user1, 500);
+
+      await program.methods
+        .fundPollPrizeToken(new BN(500))
+        .accountsPartial({
+          poll,
+          prize,
+          prizeMint,
+          creatorTokenAccount: creatorTokens,
+          prizeVault,
+          creator: user1.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+
+      const [vote] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vote"), poll.toBuffer(), user1.publicKey.toBuffer()],
+        program.programId
+      );
+      await program.methods
+        .votePoll(1)
+        .accountsPartial({
+          poll,
+          membership: membership1,
+          vote,
+          voter: user1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+
+      await sleep(7000);
+      await program.methods
+        .finalizePoll()
+        .accountsPartial({ poll, pollResult: null })
+        .rpc();
+
+      // User2 never created a token account for the prize mint
+      const winnerAta = getAssociatedTokenAddressSync(prizeMint, user2.publicKey);
+      await program.methods
+        .settlePrizePoll()
+        .accountsPartial({
+          poll,
+          prize,
+          funder: user1.publicKey,
+          prizeVault,
+          funderTokenAccount: creatorTokens,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts([
+          { pubkey: user2Profile, isSigner: false, isWritable: false },
+          { pubkey: winnerAta, isSigner: false, isWritable: true },
+        ])
+        .rpc();
+
+      const refunded = await getAccount(provider.connection, creatorTokens);
+      assert.equal(Number(refunded.amount), 500);
+      assert.isNull(await provider.connection.getAccountInfo(prize));
+    });
+
+    it("Refunds the share of a winner whose wallet would stay below rent exemption", async () => {
+      const winner = Keypair.generate();
+      await airdrop(provider.connection, winner.publicKey, 1);
+      const [winnerProfile] = PublicKey.findProgramAddressSync(
+        [Buffer.from("profile"), winner.publicKey.toBuffer()],
+        program.programId
+      );
+      await program.methods
+        .createProfile("Erin", "https://example.com/erin.jpg")
+        .accountsPartial({
+          profile: winnerProfile,
+          owner: winner.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([winner])
+        .rpc();
+
+      // Empty the winner's wallet; the provider pays the fee
+      const balance = await provider.connection.getBalance(winner.publicKey);
+      await provider.sendAndConfirm(
+        new Transaction().add(
+          SystemProgram.transfer({
+            fromPubkey: winner.publicKey,
+            toPubkey: provider.wallet.publicKey,
+            lamports: balance,
+          })
+        ),
+        [winner]
+      );
+
+      const poll = await createProfilePoll(
+        "https://example.com/poll-prize-empty-wallet.json",
+        pollConfig(),
+        5,
+        [user1Profile, winnerProfile]
+      );
+      const prize = prizeFor(poll);
+      await program.methods
+        .fundPollPrize(new BN(1000))
+        .accountsPartial({
+          poll,
+          prize,
+          creator: user1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+
+      const [vote] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vote"), poll.toBuffer(), user1.publicKey.toBuffer()],
+        program.programId
+      );
+      await program.methods
+        .votePoll(1)
+        .accountsPartial({
+          poll,
+          membership: membership1,
+          vote,
+          voter: user1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+
+      await sleep(7000);
+      await program.methods
+        .finalizePoll()
+        .accountsPartial({ poll, pollResult: null })
+        .rpc();
+
+      const prizeLamports = await provider.connection.getBalance(prize);
+      const before = await provider.connection.getBalance(user1.publicKey);
+      await program.methods
+        .settlePrizePoll()
+        .accountsPartial({
+          poll,
+          prize,
+          funder: user1.publicKey,
+          prizeVault: null,
+          funderTokenAccount: null,
+          tokenProgram: null,
+        })
+        .remainingAccounts([
+          { pubkey: winnerProfile, isSigner: false, isWritable: false },
+          { pubkey: winner.publicKey, isSigner: false, isWritable: true },
+        ])
+        .rpc();
+      const after = await provider.connection.getBalance(user1.publicKey);
+
+      assert.equal(await provider.connection.getBalance(winner.publicKey), 0);
+      assert.equal(after - before, prizeLamports);
+      assert.isNull(await provider.connection.getAccountInfo(prize));
+    });
+  });
+
+  describe("Governance Proposals", () => {
+    let descriptionPoll: PublicKey;
+    let policyPoll: PublicKey;
+    let descriptionProposal: PublicKey;
+    let policyProposal: PublicKey;
+    let moderatorPoll: PublicKey;
+
+    const createApprovalPoll = async () => {
+      const communityData = await program.account.communityAccount.fetch(community);
+      const [proposalPoll] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("poll"),
+          community.toBuffer(),
+          communityData.pollCounter.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .createPoll(
+          "https://example.com/poll-proposal.json",
+          { text: {} },
+          [],
+          ["Approve", "Reject"],
+          pollConfig({ quorum: 2 }),
+          new BN(Math.floor(Date.now() / 1000) + 5)
+        )
+        .accountsPartial({
+          community: community,
+          membership: membership1,
+          poll: proposalPoll,
+          creator: user1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+
+      return proposalPoll;
+    };
+
+    const findProposal = (proposalPoll: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalPoll.toBuffer()],
+        program.programId
+      )[0];
+
+    const createProposal = (
+      proposalPoll: PublicKey,
+      action: any,
+      proposer: Keypair,
+      proposerMembership: PublicKey,
+      thresholdBps = 6000
+    ) =>
+      program.methods
+        .createProposal(action, thresholdBps, new BN(0))
+        .accountsPartial({
+          community: community,
+          membership: proposerMembership,
+          poll: proposalPoll,
+          proposal: findProposal(proposalPoll),
+          proposer: proposer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([proposer])
+        .rpc();
+
+    const approve = async (proposalPoll: PublicKey, voter: Keypair, voterMembership: PublicKey) => {
+      const [vote] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vote"), proposalPoll.toBuffer(), voter.publicKey.toBuffer()],
+        program.programId
+      );
+      await program.methods
+        .votePoll(0)
+        .accountsPartial({
+          poll: proposalPoll,
+          membership: voterMembership,
+          vote,
+          voter: voter.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([voter])
+        .rpc();
+    };
+
+    const execute = (proposalPoll: PublicKey) =>
+      program.methods
+        .executeProposal()
+        .accountsPartial({
+          community: community,
+          poll: proposalPoll,
+          proposal: findProposal(proposalPoll),
+          memberMembership: null,
+          newModerator: null,
+          removedModerator: null,
+          executor: user3.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user3])
+        .rpc();
+
+    before(async () => {
+      descriptionPoll = await createApprovalPoll();
+      policyPoll = await createApprovalPoll();
+      descriptionProposal = findProposal(descriptionPoll);
+      policyProposal = findProposal(policyPoll);
+      moderatorPoll = await createApprovalPoll();
+    });
+
+    it("Fails to attach a proposal to someone else's poll", async () => {
+      try {
+        await createProposal(
+          descriptionPoll,
+          { setJoinFee: { joinFee: new BN(1) } },
+          user2,
+          membership2
+        );
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("NotPollCreator"));
+      }
+    });
+
+    it("Fails to create a proposal below the community minimums", async () => {
+      try {
+        await createProposal(
+          descriptionPoll,
+          { setJoinFee: { joinFee: new BN(1) } },
+          user1,
+          membership1,
+          1
+        );
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("BelowGovernanceMinimum"));
+      }
+    });
+
+    it("Creates proposals and members approve them", async () => {
+      await createProposal(
+        descriptionPoll,
+        { updateDescription: { descriptionUri: "https://example.com/community-desc-v2.json" } },
+        user1,
+        membership1
+      );
+      await createProposal(
+        policyPoll,
+        { setJoinPolicy: { joinPolicy: { closed: {} } } },
+        user1,
+        membership1
+      );
+      await createProposal(
+        moderatorPoll,
+        { addModerator: { user: user2.publicKey } },
+        user1,
+        membership1
+      );
+
+      for (const proposalPoll of [descriptionPoll, policyPoll, moderatorPoll]) {
+        await approve(proposalPoll, user1, membership1);
+        await approve(proposalPoll, user2, membership2);
+      }
+
+      const proposalData = await program.account.proposalAccount.fetch(descriptionProposal);
+      assert.ok(proposalData.poll.equals(descriptionPoll));
+      assert.equal(proposalData.thresholdBps, 6000);
+      assert.isNull(proposalData.executedAt);
+    });
+
+    it("Fails to execute before the poll is finalized", async () => {
+      try {
+        await execute(descriptionPoll);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("ProposalNotPassed"));
+      }
+    });
+
+    it("Executes a passed proposal", async () => {
+      await sleep(7000);
+      for (const proposalPoll of [descriptionPoll, policyPoll, moderatorPoll]) {
+        await program.methods
+          .finalizePoll()
+          .accountsPartial({ poll: proposalPoll, pollResult: null })
+          .rpc();
+      }
+
+      await execute(descriptionPoll);
+
+      const communityData = await program.account.communityAccount.fetch(community);
+      assert.equal(communityData.descriptionUri, "https://example.com/community-desc-v2.json");
+
+      const proposalData = await program.account.proposalAccount.fetch(descriptionProposal);
+      assert.isNotNull(proposalData.executedAt);
+    });
+
+    it("Fails to execute a proposal twice", async () => {
+      try {
+        await execute(descriptionPoll);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("ProposalAlreadyExecuted"));
+      }
+    });
+
+    it("Re-adds an existing moderator", async () => {
+      await program.methods
+        .executeProposal()
+        .accountsPartial({
+          community: community,
+          poll: moderatorPoll,
+          proposal: findProposal(moderatorPoll),
+          memberMembership: membership2,
+          newModerator: moderator2,
+          removedModerator: null,
+          executor: user3.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user3])
+        .rpc();
+
+      const moderatorData = await program.account.moderatorAccount.fetch(moderator2);
+      assert.ok(moderatorData.user.equals(user2.publicKey));
+      assert.ok(moderatorData.addedBy.equals(findProposal(moderatorPoll)));
+      assert.isNull(moderatorData.termEndsAt);
+    });
+
+    it("Fails to close a passed proposal before it is executed", async () => {
+      try {
+        await program.methods
+          .closeProposal()
+          .accountsPartial({
+            poll: policyPoll,
+            proposal: policyProposal,
+            proposer: user1.publicKey,
+          })
+          .signers([user1])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("ProposalNotSettled"));
+      }
+    });
+
+    it("Proposer closes an executed proposal", async () => {
+      await program.methods
+        .closeProposal()
+        .accountsPartial({
+          poll: descriptionPoll,
+          proposal: descriptionProposal,
+          proposer: user1.publicKey,
+        })
+        .signers([user1])
+        .rpc();
+
+      assert.isNull(await provider.connection.getAccountInfo(descriptionProposal));
+    });
+
+    it("Closes the community to new members", async () => {
+      await execute(policyPoll);
+
+      const communityData = await program.account.communityAccount.fetch(community);
+      assert.ok(communityData.joinPolicy.closed !== undefined);
+
+      const [membership3] = PublicKey.findProgramAddressSync(
+        [Buffer.from("membership"), community.toBuffer(), user3.publicKey.toBuffer()],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .joinCommunity()
+          .accountsPartial({
+            community: community,
+            creator: user1.publicKey,
+            userProfile: user3Profile,
+            membership: membership3,
+            subscription: null,
+            user: user3.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user3])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.toString().includes("CommunityClosed"));
+      }
+    });
+  });
+
+  describe("Edge Cases and Security", () => {
+    it("Non-member cannot create post", async () => {
+      const [nonMemberPost] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("post"),
+          community.toBuffer(),
+          new BN(10).toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      const [fakeMembership] = PublicKey.findProgramAddressSync(
+        [Buffer.from("membership"), community.toBuffer(), user3.publicKey.toBuffer()],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .createPost(
+            "https://example.com/post-bad.json",
+            contentHash,
+            false,
+            null
+          )
+          .accountsPartial({
+            community: community,
+            membership: fakeMembership,
+            post: nonMemberPost,
+            author: user3.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user3])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.ok(err.message.includes("AccountNotInitialized") || err.message.includes("AnchorError"));
+      }
+    });
+
+    it("Content URI too long fails", async () => {
+      const longUri = "https://example.com/" + "a".repeat(200);
+      const [longPost] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("post"),
+          community.toBuffer(),
+          new BN(11).toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .createPost(longUri, contentHash, false, null)
+          .accountsPartial({
+            community: community,
+            membership: membership1,
+            post: longPost,
+            author: user1.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user1])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        // The error could be ContentUriTooLong or a serialization error
+        assert.ok(err.toString().includes("ContentUriTooLong") || err.toString().includes("Error Code"));
+      }
+    });
+  });
+});
+
+// Helper function to airdrop SOL
+async function airdrop(connection: any, address: PublicKey, amount: number) {
+  const signature = await connection.requestAirdrop(
+    address,
+    amount * LAMPORTS_PER_SOL
+  );
+  const latestBlockhash = await connection.getLatestBlockhash();
+  await connection.confirmTransaction({
+    signature,
+    ...latestBlockhash,
+  });
+}
+
+// Helper function to derive the block PDA from an author to another user
+function findBlock(programId: PublicKey, author: PublicKey, user: PublicKey): PublicKey {
+  const [authorProfile] = PublicKey.findProgramAddressSync(
+    [Buffer.from("profile"), author.toBuffer()],
+    programId
+  );
+  const [userProfile] = PublicKey.findProgramAddressSync(
+    [Buffer.from("profile"), user.toBuffer()],
+    programId
+  );
+  const [block] = PublicKey.findProgramAddressSync(
+    [Buffer.from("block"), authorProfile.toBuffer(), userProfile.toBuffer()],
+    programId
+  );
+  return block;
+}
+
+// Helper function to wait for on-chain time to pass a deadline
+function sleep(ms: number): Promise<void> {
+  return new Promise((resolve) => setTimeout(resolve, ms));
+}
+
+// Helper function to build a poll config, defaulting to an open single-choice poll
+function pollConfig(overrides: Record<string, any> = {}) {
+  return {
+    mode: { singleChoice: {} } as Record<string, object>,
+    maxSelections: 0,
+    allowVoteChange: false,
+    weightMint: null as PublicKey | null,
+    revealEndTime: null as BN | null,
+    startTime: null as BN | null,
+    quorum: 0,
+    minMembershipAge: new BN(0),
+    electionSeats: 0,
+    termLength: new BN(0),
+    ...overrides,
+  };
+}